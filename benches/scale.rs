@@ -0,0 +1,78 @@
+//! Compares `SafeDec` operations that scale by `10^D` against recomputing the factor per call.
+//!
+//! Run with `cargo bench --bench scale`.
+
+use core::hint::black_box;
+use std::time::Instant;
+
+use safe_bigmath::{SafeDec, SafeInt};
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, mut f: impl FnMut()) -> f64 {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let nanos = start.elapsed().as_nanos() as f64 / f64::from(ITERATIONS);
+    println!("{name:<32} {nanos:>9.1} ns/op");
+    nanos
+}
+
+fn compare(name: &str, cached: impl FnMut(), uncached: impl FnMut()) {
+    let new = bench(&format!("{name} (cached)"), cached);
+    let old = bench(&format!("{name} (recomputed)"), uncached);
+    println!("{:<32} {:>9.2}x\n", "speedup", old / new);
+}
+
+fn main() {
+    let a: SafeDec<18> = "12345.678901234567890123".parse().unwrap();
+    let b: SafeDec<18> = "3.141592653589793238".parse().unwrap();
+    let scale = || SafeInt::from(10).pow(18);
+
+    compare(
+        "mul",
+        || {
+            black_box(black_box(&a).clone() * black_box(&b).clone());
+        },
+        || {
+            let product = black_box(&a).raw().clone() * black_box(&b).raw().clone();
+            black_box(SafeDec::<18>::from_raw((product / scale()).unwrap()));
+        },
+    );
+    compare(
+        "div",
+        || {
+            black_box(black_box(&a) / black_box(&b));
+        },
+        || {
+            let scaled = black_box(&a).raw() * scale();
+            black_box(scaled / black_box(&b).raw());
+        },
+    );
+    compare(
+        "cmp with primitive",
+        || {
+            black_box(black_box(&a) < black_box(&12_346u64));
+        },
+        || {
+            black_box(black_box(&a).raw() < &(SafeInt::from(black_box(12_346u64)) * scale()));
+        },
+    );
+    compare(
+        "to_safe_int",
+        || {
+            black_box(black_box(&a).to_safe_int(safe_bigmath::RoundingMode::HalfEven));
+        },
+        || {
+            black_box(
+                black_box(&a)
+                    .raw()
+                    .div_round(&scale(), safe_bigmath::RoundingMode::HalfEven),
+            );
+        },
+    );
+}
@@ -0,0 +1,451 @@
+//! Arithmetic on large magnitudes, optionally handed to a faster pure-Rust crate.
+//!
+//! Values are stored as `num-bigint` integers whichever backend is active, so the wire format
+//! does not depend on it. The public API exchanges values through backend-neutral limbs and
+//! bytes ([`SafeInt::to_u64_digits`](crate::SafeInt::to_u64_digits) and friends); the
+//! `BigInt`-typed `raw()`/`from_raw()` are deprecated. Multiplication and division are where
+//! `num-bigint` falls behind on big operands (its division is quadratic), so once both operands
+//! reach [`DELEGATE_MIN_BITS`] these go through the [`Active`] backend, converting limbs in
+//! linear time. The `backend-malachite` feature takes precedence over `backend-ibig`; with
+//! neither enabled everything stays on `num-bigint`.
+// Every enabled backend is compiled so the conformance tests can compare them, but only the
+// active one is reachable outside of tests.
+#![cfg_attr(not(test), allow(dead_code))]
+
+#[cfg(test)]
+use alloc::vec;
+#[cfg(any(test, feature = "backend-malachite"))]
+use alloc::vec::Vec;
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+
+/// Operands below this many bits are handled by `num-bigint` directly, since converting them
+/// would cost more than a backend saves.
+pub(crate) const DELEGATE_MIN_BITS: u64 = 4_096;
+
+/// Multiplication and truncating division of magnitudes.
+pub(crate) trait Backend {
+    /// Name used in diagnostics.
+    const NAME: &'static str;
+
+    /// Returns `a * b`.
+    fn mul(a: &BigUint, b: &BigUint) -> BigUint;
+
+    /// Returns `(a / b, a % b)` for a non-zero `b`.
+    fn div_rem(a: &BigUint, b: &BigUint) -> (BigUint, BigUint);
+}
+
+/// The reference backend: `num-bigint` itself.
+pub(crate) struct NumBigint;
+
+impl Backend for NumBigint {
+    const NAME: &'static str = "num-bigint";
+
+    #[inline(always)]
+    fn mul(a: &BigUint, b: &BigUint) -> BigUint {
+        a * b
+    }
+
+    #[inline(always)]
+    fn div_rem(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+        a.div_rem(b)
+    }
+}
+
+/// Backend built on `ibig`.
+#[cfg(feature = "backend-ibig")]
+pub(crate) struct Ibig;
+
+#[cfg(feature = "backend-ibig")]
+impl Ibig {
+    #[inline(always)]
+    fn to_ubig(value: &BigUint) -> ibig::UBig {
+        ibig::UBig::from_le_bytes(&value.to_bytes_le())
+    }
+
+    #[inline(always)]
+    fn from_ubig(value: &ibig::UBig) -> BigUint {
+        BigUint::from_bytes_le(&value.to_le_bytes())
+    }
+}
+
+#[cfg(feature = "backend-ibig")]
+impl Backend for Ibig {
+    const NAME: &'static str = "ibig";
+
+    fn mul(a: &BigUint, b: &BigUint) -> BigUint {
+        Ibig::from_ubig(&(Ibig::to_ubig(a) * Ibig::to_ubig(b)))
+    }
+
+    fn div_rem(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+        use ibig::ops::DivRem;
+        let (quotient, remainder) = Ibig::to_ubig(a).div_rem(Ibig::to_ubig(b));
+        (Ibig::from_ubig(&quotient), Ibig::from_ubig(&remainder))
+    }
+}
+
+/// Backend built on `malachite-nz`.
+#[cfg(feature = "backend-malachite")]
+pub(crate) struct Malachite;
+
+#[cfg(feature = "backend-malachite")]
+impl Malachite {
+    #[inline(always)]
+    fn to_natural(value: &BigUint) -> malachite_nz::natural::Natural {
+        malachite_nz::natural::Natural::from_owned_limbs_asc(value.to_u64_digits())
+    }
+
+    #[inline(always)]
+    fn from_natural(value: malachite_nz::natural::Natural) -> BigUint {
+        let limbs: Vec<u64> = value.into_limbs_asc();
+        let bytes: Vec<u8> = limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+        BigUint::from_bytes_le(&bytes)
+    }
+}
+
+#[cfg(feature = "backend-malachite")]
+impl Backend for Malachite {
+    const NAME: &'static str = "malachite";
+
+    fn mul(a: &BigUint, b: &BigUint) -> BigUint {
+        Malachite::from_natural(Malachite::to_natural(a) * Malachite::to_natural(b))
+    }
+
+    fn div_rem(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+        use malachite_base::num::arithmetic::traits::DivRem;
+        let (quotient, remainder) = Malachite::to_natural(a).div_rem(Malachite::to_natural(b));
+        (
+            Malachite::from_natural(quotient),
+            Malachite::from_natural(remainder),
+        )
+    }
+}
+
+/// The backend selected by cargo features.
+#[cfg(feature = "backend-malachite")]
+pub(crate) type Active = Malachite;
+/// The backend selected by cargo features.
+#[cfg(all(feature = "backend-ibig", not(feature = "backend-malachite")))]
+pub(crate) type Active = Ibig;
+/// The backend selected by cargo features.
+#[cfg(not(any(feature = "backend-ibig", feature = "backend-malachite")))]
+pub(crate) type Active = NumBigint;
+
+#[cfg(test)]
+std::thread_local! {
+    /// Set by [`with_reference`] to keep the current thread on plain `num-bigint`.
+    static REFERENCE: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Runs `f` with every operation on the calling thread routed through `num-bigint`, giving the
+/// reference result the active backend is compared against.
+#[cfg(test)]
+fn with_reference<R>(f: impl FnOnce() -> R) -> R {
+    REFERENCE.with(|reference| reference.set(true));
+    let result = f();
+    REFERENCE.with(|reference| reference.set(false));
+    result
+}
+
+/// Returns `true` if operands of `bits` bits skip the active backend.
+#[inline(always)]
+fn stay_on_num_bigint(bits: u64) -> bool {
+    #[cfg(test)]
+    if REFERENCE.with(core::cell::Cell::get) {
+        return true;
+    }
+    bits < DELEGATE_MIN_BITS
+}
+
+/// Multiplies magnitudes, delegating to the active backend when both are large.
+#[inline(always)]
+pub(crate) fn mul_unsigned(a: &BigUint, b: &BigUint) -> BigUint {
+    if stay_on_num_bigint(a.bits().min(b.bits())) {
+        return a * b;
+    }
+    Active::mul(a, b)
+}
+
+/// Multiplies signed values, delegating to the active backend when both are large.
+#[inline(always)]
+pub(crate) fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    if stay_on_num_bigint(a.bits().min(b.bits())) {
+        return a * b;
+    }
+    BigInt::from_biguint(
+        a.sign() * b.sign(),
+        Active::mul(a.magnitude(), b.magnitude()),
+    )
+}
+
+/// Truncating division with the same signs as `BigInt::div_rem`: the quotient rounds toward
+/// zero and the remainder takes the sign of `a`. `b` must be non-zero.
+#[inline(always)]
+pub(crate) fn div_rem(a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+    if stay_on_num_bigint(b.bits()) || a.bits() < b.bits() {
+        return a.div_rem(b);
+    }
+    let (quotient, remainder) = Active::div_rem(a.magnitude(), b.magnitude());
+    (
+        BigInt::from_biguint(a.sign() * b.sign(), quotient),
+        BigInt::from_biguint(a.sign(), remainder),
+    )
+}
+
+/// Divides magnitudes, delegating to the active backend when both are large. `b` must be
+/// non-zero.
+#[inline(always)]
+pub(crate) fn div_rem_unsigned(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+    if stay_on_num_bigint(b.bits()) || a.bits() < b.bits() {
+        return a.div_rem(b);
+    }
+    Active::div_rem(a, b)
+}
+
+/// Raises `base` to `exp` by squaring, so large intermediate products use the backend.
+pub(crate) fn pow(base: &BigInt, exp: u32) -> BigInt {
+    if stay_on_num_bigint(base.bits().saturating_mul(u64::from(exp)) / 2) {
+        return base.pow(exp);
+    }
+    let mut result = BigInt::from(1u8);
+    let mut square = base.clone();
+    let mut exp = exp;
+    loop {
+        if exp & 1 == 1 {
+            result = mul(&result, &square);
+        }
+        exp >>= 1;
+        if exp == 0 {
+            return result;
+        }
+        square = mul(&square, &square);
+    }
+}
+
+#[cfg(test)]
+fn conformance_operands() -> Vec<BigUint> {
+    use num_traits::One;
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    let mut random = |bits: usize| {
+        let bytes: Vec<u8> = (0..bits.div_ceil(8))
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        BigUint::from_bytes_le(&bytes) | (BigUint::one() << (bits - 1))
+    };
+    let edge = BigUint::one() << DELEGATE_MIN_BITS;
+    vec![
+        BigUint::ZERO,
+        BigUint::one(),
+        BigUint::from(u64::MAX),
+        &edge - 1u8,
+        edge,
+        random(4_500),
+        random(9_001),
+        random(20_000),
+    ]
+}
+
+#[cfg(test)]
+fn check_backend<B: Backend>(operands: &[BigUint]) {
+    for a in operands {
+        for b in operands {
+            assert_eq!(B::mul(a, b), a * b, "{} mul", B::NAME);
+            if b.bits() > 0 {
+                assert_eq!(B::div_rem(a, b), a.div_rem(b), "{} div_rem", B::NAME);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_backend_conformance() {
+    let operands = conformance_operands();
+    check_backend::<NumBigint>(&operands);
+    #[cfg(feature = "backend-ibig")]
+    check_backend::<Ibig>(&operands);
+    #[cfg(feature = "backend-malachite")]
+    check_backend::<Malachite>(&operands);
+}
+
+/// Every public operation under the active backend must match the same operation run on plain
+/// `num-bigint`, both through [`with_reference`] and, where the formula is simple, against
+/// `num-bigint` arithmetic directly. CI runs this once per backend feature.
+#[test]
+fn test_public_ops_conformance() {
+    use crate::{RoundingMode, SafeDec, SafeInt, SafeUdec, SafeUint};
+    use alloc::{format, string::ToString};
+    use lencode::Encode;
+    use num_integer::Integer;
+
+    macro_rules! conform {
+        ($($op:expr),* $(,)?) => {
+            $(
+                let active = $op;
+                assert_eq!(active, with_reference(|| $op), "{}: {}", Active::NAME, stringify!($op));
+            )*
+        };
+    }
+
+    let magnitudes = conformance_operands();
+    let mut values = Vec::new();
+    for magnitude in &magnitudes {
+        let value = BigInt::from(magnitude.clone());
+        values.push(SafeInt::from_big(-&value));
+        values.push(SafeInt::from_big(value));
+    }
+
+    for x in &values {
+        let a = x.big();
+        assert_eq!(*x.clone().pow(3).big(), a.pow(3), "{}", Active::NAME);
+        conform!(
+            x.clone().pow(3),
+            x.clone().abs(),
+            -x,
+            x.to_string(),
+            x.to_string().parse::<SafeInt>().unwrap(),
+            x.to_str_radix(16),
+            x.to_str_radix(7),
+            SafeInt::from_str_radix(&x.to_str_radix(36).unwrap(), 36).unwrap(),
+            x.to_u64_digits(),
+            x.to_signed_bytes_le(),
+            x.to_signed_bytes_be(),
+            x.to_bytes_le(),
+            SafeInt::from_signed_bytes_be(&x.to_signed_bytes_be()),
+            x.to_f64(),
+            {
+                let mut bytes = Vec::new();
+                x.encode(&mut bytes).unwrap();
+                bytes
+            },
+        );
+        if !x.is_negative() && !x.is_zero() && x.big().bits() <= 9_001 {
+            conform!(x.log10(&SafeInt::from(10).pow(30), 64, None));
+        }
+
+        for y in &values {
+            let b = y.big();
+            let product = x * y;
+            assert_eq!(*product.big(), &*a * &*b, "{}", Active::NAME);
+            let mut assigned = x.clone();
+            assigned *= y;
+            assert_eq!(assigned, product);
+            conform!(x * y, x + y, x - y, x & y, x | y, x ^ y, x.cmp(y));
+            if y.is_zero() {
+                assert_eq!(x / y, None);
+                continue;
+            }
+            let (quotient, remainder) = a.div_rem(&b);
+            assert_eq!(*(x / y).unwrap().big(), quotient, "{}", Active::NAME);
+            assert_eq!(*(x % y).unwrap().big(), remainder, "{}", Active::NAME);
+            assert_eq!(
+                x.clone().div_rem(y.clone()).unwrap(),
+                (SafeInt::from_big(quotient), SafeInt::from_big(remainder))
+            );
+            assert_eq!(
+                *x.div_round(y, RoundingMode::Floor).unwrap().big(),
+                a.div_floor(&b)
+            );
+            conform!(
+                x / y,
+                x % y,
+                x.clone().div_rem(y.clone()),
+                x.ceil_div(y.clone()),
+            );
+            for mode in RoundingMode::ALL {
+                conform!(x.div_round(y, mode));
+            }
+        }
+    }
+
+    // Unsigned values share the magnitude paths.
+    for a in &magnitudes {
+        let x = SafeUint::from_u64_digits(&a.to_u64_digits());
+        for b in &magnitudes {
+            let y = SafeUint::from_u64_digits(&b.to_u64_digits());
+            conform!(&x * &y, &x / &y, &x % &y, &x - &y);
+        }
+    }
+
+    // A scale of 10^1300 (about 4300 bits) sends decimal rescaling through the backend.
+    let decimals: Vec<SafeDec<1300>> = values
+        .iter()
+        .map(|value| SafeDec::from_raw(value.clone()))
+        .collect();
+    for x in &decimals {
+        conform!(
+            x.to_string(),
+            format!("{x:.40}"),
+            x.to_string().parse::<SafeDec<1300>>().unwrap(),
+            x.to_f64(),
+            x.round_to(17, RoundingMode::HalfEven),
+            SafeDec::<18>::from_other_scale(x.clone()),
+        );
+        for mode in RoundingMode::ALL {
+            conform!(x.round(mode), x.to_safe_int(mode));
+        }
+        for y in &decimals {
+            conform!(x.clone() * y.clone(), x / y, x % y);
+        }
+    }
+    let unsigned: Vec<SafeUdec<1300>> = magnitudes
+        .iter()
+        .map(|magnitude| SafeUdec::from_raw(SafeUint::from_u64_digits(&magnitude.to_u64_digits())))
+        .collect();
+    for x in &unsigned {
+        for y in &unsigned {
+            conform!(x * y, x / y, x.to_string());
+        }
+    }
+
+    // Divide-and-conquer digit conversion at sizes far past the backend threshold.
+    let huge = SafeInt::from(7).pow(100_000);
+    assert_eq!(huge.to_string(), huge.big().to_str_radix(10));
+    conform!(
+        huge.to_string(),
+        huge.to_string().parse::<SafeInt>().unwrap(),
+        SafeInt::from_str_radix(&huge.to_str_radix(16).unwrap(), 16).unwrap(),
+    );
+    let price = SafeDec::<18>::from_raw(SafeInt::from(3).pow(6_000));
+    let total = price.clone() * price;
+    let exact = SafeInt::from(3).pow(12_000);
+    assert_eq!(*total.raw(), (exact / SafeInt::from(10).pow(18)).unwrap());
+
+    let x = SafeInt::from(21_000_000_000_000_000u64);
+    let dx = SafeInt::from(7_000_000_000_000_000u64);
+    let w1 = SafeInt::from(600_000_000_000_000_000u128);
+    let w2 = SafeInt::from(400_000_000_000_000_000u128);
+    let perquintill = SafeInt::from(1_000_000_000_000_000_000u128);
+    assert_eq!(
+        SafeInt::pow_ratio_scaled(&x, &(&x + &dx), &w1, &w2, 0, &perquintill).unwrap(),
+        649_519_052_838_328_985u128
+    );
+    let scale = SafeInt::from(10).pow(1_300);
+    let root = SafeInt::pow_ratio_scaled(
+        &SafeInt::from(2),
+        &SafeInt::one(),
+        &SafeInt::one(),
+        &SafeInt::from(2),
+        0,
+        &scale,
+    )
+    .unwrap();
+    assert_eq!(root.to_string()[..20], *"14142135623730950488");
+    assert_eq!(root.to_string().len(), 1_301);
+    conform!(
+        SafeInt::pow_ratio_scaled(&x, &(&x + &dx), &w1, &w2, 0, &scale),
+        SafeInt::pow_bigint_base(&SafeInt::from(u64::MAX), &w1, &w2, 0, &scale),
+        SafeInt::pow_bigint_base(
+            &SafeInt::from(3),
+            &SafeInt::one(),
+            &SafeInt::from(3),
+            64,
+            &scale
+        ),
+        SafeInt::from(10).pow(2_000).log10(&scale, 0, None),
+    );
+}
@@ -0,0 +1,189 @@
+//! Subquadratic conversions between digit strings and big integers.
+//!
+//! `num-bigint` converts one machine word of digits at a time, which is quadratic in the number
+//! of digits. Past tens of thousands of digits these helpers split the work in halves around
+//! precomputed powers of the radix, so the cost is dominated by big multiplications
+//! (Karatsuba/Toom-3). Printing replaces division by each power with a multiplication by its
+//! Newton reciprocal, since `num-bigint` division is also quadratic.
+
+use crate::backend;
+use alloc::{string::String, vec::Vec};
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(test)]
+use num_integer::Integer;
+use num_traits::One;
+#[cfg(test)]
+use num_traits::Zero;
+
+/// Inputs up to this many digits are parsed directly by `num-bigint`.
+const PARSE_DIRECT_DIGITS: usize = 32_768;
+/// Digits in the smallest chunk that is parsed directly once an input is split.
+const PARSE_LEAF_DIGITS: usize = 1_024;
+/// Values up to this many bits (about 79k decimal digits) are printed directly by `num-bigint`.
+const PRINT_DIRECT_BITS: u64 = 1 << 18;
+/// Decimal digits in the smallest power of ten used to split a value for printing.
+const PRINT_LEAF_DIGITS: usize = 1_024;
+/// Divisors at or below this many bits get their reciprocal by plain division.
+const RECIPROCAL_LEAF_BITS: u64 = 4_096;
+
+/// Builds the value of `digits` (most significant first, each below `radix`).
+pub(crate) fn from_digits(digits: &[u8], radix: u32) -> BigUint {
+    if digits.len() <= PARSE_DIRECT_DIGITS || radix.is_power_of_two() {
+        // Power-of-two radixes are already linear: digits map straight onto bits.
+        return BigUint::from_radix_be(digits, radix).unwrap_or_default();
+    }
+    let mut powers = Vec::new();
+    let mut power = BigUint::from(radix).pow(PARSE_LEAF_DIGITS as u32);
+    while PARSE_LEAF_DIGITS << powers.len() < digits.len() {
+        let next = backend::mul_unsigned(&power, &power);
+        powers.push(power);
+        power = next;
+    }
+    combine(digits, radix, &powers)
+}
+
+/// `powers[k]` is `radix^(PARSE_LEAF_DIGITS << k)`.
+fn combine(digits: &[u8], radix: u32, powers: &[BigUint]) -> BigUint {
+    if digits.len() <= PARSE_LEAF_DIGITS {
+        return BigUint::from_radix_be(digits, radix).unwrap_or_default();
+    }
+    let level =
+        (usize::BITS - ((digits.len() - 1) / PARSE_LEAF_DIGITS).leading_zeros() - 1) as usize;
+    let (high, low) = digits.split_at(digits.len() - (PARSE_LEAF_DIGITS << level));
+    backend::mul_unsigned(&combine(high, radix, powers), &powers[level])
+        + combine(low, radix, powers)
+}
+
+/// Writes `value` in decimal without leading zeros (`"0"` for zero).
+pub(crate) fn to_decimal(value: &BigUint) -> String {
+    if value.bits() <= PRINT_DIRECT_BITS {
+        return value.to_str_radix(10);
+    }
+    let mut divisors: Vec<Divisor> = Vec::new();
+    let mut power = BigUint::from(10u8).pow(PRINT_LEAF_DIGITS as u32);
+    loop {
+        let square = backend::mul_unsigned(&power, &power);
+        divisors.push(Divisor::new(power));
+        if &square > value {
+            break;
+        }
+        power = square;
+    }
+    let mut out = String::with_capacity(value.bits() as usize * 31 / 100 + 1);
+    write_decimal(value, None, &divisors, &mut out);
+    out
+}
+
+/// Appends `value`, left-padded with zeros to `width` digits when given. `value` is below the
+/// square of the last divisor, and below `10^width` when `width` is set.
+fn write_decimal(value: &BigUint, width: Option<usize>, divisors: &[Divisor], out: &mut String) {
+    let Some((divisor, smaller)) = divisors.split_last() else {
+        let digits = value.to_str_radix(10);
+        if let Some(width) = width {
+            out.extend(core::iter::repeat_n('0', width - digits.len()));
+        }
+        out.push_str(&digits);
+        return;
+    };
+    if width.is_none() && value < &divisor.power {
+        return write_decimal(value, None, smaller, out);
+    }
+    let low_digits = PRINT_LEAF_DIGITS << smaller.len();
+    let (quotient, remainder) = divisor.div_rem(value);
+    write_decimal(
+        &quotient,
+        width.map(|width| width - low_digits),
+        smaller,
+        out,
+    );
+    write_decimal(&remainder, Some(low_digits), smaller, out);
+}
+
+/// A power of ten with its reciprocal `floor(2^(2 * bits) / power)`.
+struct Divisor {
+    power: BigUint,
+    reciprocal: BigUint,
+}
+
+impl Divisor {
+    fn new(power: BigUint) -> Self {
+        let reciprocal = reciprocal(&power);
+        Divisor { power, reciprocal }
+    }
+
+    /// Divides a `value` below `power^2`.
+    fn div_rem(&self, value: &BigUint) -> (BigUint, BigUint) {
+        let bits = self.power.bits();
+        // Only the top half of `value` matters for the estimate, which is never above the
+        // true quotient and at most a few below it.
+        let mut quotient =
+            backend::mul_unsigned(&(value >> (bits - 1)), &self.reciprocal) >> (bits + 1);
+        let mut remainder = value - backend::mul_unsigned(&quotient, &self.power);
+        while remainder >= self.power {
+            remainder -= &self.power;
+            quotient += 1u8;
+        }
+        (quotient, remainder)
+    }
+}
+
+/// Computes `floor(2^(2 * bits) / divisor)` for a non-zero `divisor` of `bits` bits, using a
+/// Newton step from the reciprocal of its top half.
+fn reciprocal(divisor: &BigUint) -> BigUint {
+    let bits = divisor.bits();
+    let target = BigUint::one() << (2 * bits);
+    if bits <= RECIPROCAL_LEAF_BITS {
+        return target / divisor;
+    }
+    let shift = bits - (bits / 2 + 1);
+    // floor(2^(2h) / (divisor >> shift)) scaled up approximates 2^(2 * bits) / divisor.
+    let estimate = BigInt::from(reciprocal(&(divisor >> shift)) << shift);
+    let target = BigInt::from(target);
+    let divisor = BigInt::from(divisor.clone());
+    let error = &target - backend::mul(&divisor, &estimate);
+    let step = backend::mul(&estimate, &error) >> (2 * bits);
+    // The Newton step leaves a small error; settle it exactly against the remainder.
+    let mut remainder = error - backend::mul(&divisor, &step);
+    let mut result = estimate + step;
+    while remainder.sign() == Sign::Minus {
+        remainder += &divisor;
+        result -= 1;
+    }
+    while remainder >= divisor {
+        remainder -= &divisor;
+        result += 1;
+    }
+    result.into_parts().1
+}
+
+#[cfg(test)]
+fn check_round_trip(value: &BigUint) {
+    let text = to_decimal(value);
+    assert_eq!(text, value.to_str_radix(10));
+    let digits: Vec<u8> = text.bytes().map(|b| b - b'0').collect();
+    assert_eq!(&from_digits(&digits, 10), value);
+}
+
+#[test]
+fn test_large_digit_conversions() {
+    let mut value = BigUint::from(7u8).pow(100_000);
+    check_round_trip(&value);
+    // Long runs of zeros exercise the zero padding of low halves.
+    value = BigUint::from(10u8).pow(90_000) + 1u8;
+    check_round_trip(&value);
+    value = BigUint::from(10u8).pow(PRINT_LEAF_DIGITS as u32 * 80);
+    check_round_trip(&value);
+    check_round_trip(&(value - 1u8));
+    check_round_trip(&BigUint::zero());
+
+    let digits: Vec<u8> = (0..40_000).map(|i| (i * 7 % 36) as u8).collect();
+    assert_eq!(
+        from_digits(&digits, 36),
+        BigUint::from_radix_be(&digits, 36).unwrap()
+    );
+    for bits in [RECIPROCAL_LEAF_BITS + 1, 10_007, 33_333] {
+        let divisor = (BigUint::one() << (bits - 1)) + 12_345u32;
+        let expected = (BigUint::one() << (2 * bits)).div_floor(&divisor);
+        assert_eq!(reciprocal(&divisor), expected);
+    }
+}
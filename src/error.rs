@@ -0,0 +1,174 @@
+//! Error types shared by every numeric type, including the ones available without `alloc`.
+
+use core::fmt;
+
+/// Error returned by the `TryFrom` conversions from the crate's numeric types
+/// into primitive integers and narrower types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionError {
+    /// The value is greater than the target type's maximum.
+    Overflow {
+        /// Name of the target primitive type.
+        target: &'static str,
+    },
+    /// The value is less than the target type's minimum.
+    Underflow {
+        /// Name of the target primitive type.
+        target: &'static str,
+    },
+    /// The decimal value has a non-zero fractional part.
+    Fractional {
+        /// Name of the target primitive type.
+        target: &'static str,
+    },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Overflow { target } => {
+                write!(f, "value is greater than the maximum {target}")
+            }
+            ConversionError::Underflow { target } => {
+                write!(f, "value is less than the minimum {target}")
+            }
+            ConversionError::Fractional { target } => {
+                write!(
+                    f,
+                    "value has a fractional part and cannot convert to {target}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConversionError {}
+
+/// Structured error returned when parsing one of the crate's numeric types from a string.
+///
+/// Indices are character offsets into the input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// The input ended where a digit was required (for example `"-"` or `"0x"`).
+    MissingDigits {
+        /// Position where a digit was expected.
+        index: usize,
+    },
+    /// A character inside the number is not a valid digit.
+    InvalidDigit {
+        /// Position of the offending character.
+        index: usize,
+        /// The offending character.
+        found: char,
+    },
+    /// The decimal has more fractional digits than its scale allows.
+    TooManyFractionalDigits {
+        /// Maximum number of fractional digits (the scale `D`).
+        expected: usize,
+        /// Number of fractional digits in the input.
+        actual: usize,
+    },
+    /// The decimal point, or the digits after it, are missing.
+    MissingFractionalDigits {
+        /// Position where the decimal point or fractional digits were expected.
+        index: usize,
+    },
+    /// A complete number is followed by other text.
+    TrailingCharacters {
+        /// Position of the first trailing character.
+        index: usize,
+    },
+    /// The exponent in scientific notation exceeds `MAX_EXPONENT` (10,000) in magnitude.
+    ExponentOutOfRange {
+        /// Position of the exponent's first digit.
+        index: usize,
+    },
+    /// A digit-group separator does not match the expected grouping style.
+    MisplacedGroupSeparator {
+        /// Position of the offending separator.
+        index: usize,
+    },
+    /// The requested radix is outside `2..=36`.
+    UnsupportedRadix {
+        /// The requested radix.
+        radix: u32,
+    },
+    /// The value does not fit the target type, such as a fixed-size
+    /// [`SafeIntN`](crate::SafeIntN) or a negative literal for `SafeUint`.
+    OutOfRange,
+    /// A `quoth` error that does not correspond to one of the variants above.
+    #[cfg(feature = "alloc")]
+    Other {
+        /// Start of the error's span.
+        index: usize,
+        /// The error message.
+        message: alloc::string::String,
+    },
+}
+
+impl ParseError {
+    /// Position in the input the error refers to, if it points at one.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ParseError::MissingDigits { index }
+            | ParseError::InvalidDigit { index, .. }
+            | ParseError::MissingFractionalDigits { index }
+            | ParseError::TrailingCharacters { index }
+            | ParseError::ExponentOutOfRange { index }
+            | ParseError::MisplacedGroupSeparator { index } => Some(*index),
+            #[cfg(feature = "alloc")]
+            ParseError::Other { index, .. } => Some(*index),
+            ParseError::Empty
+            | ParseError::OutOfRange
+            | ParseError::TooManyFractionalDigits { .. }
+            | ParseError::UnsupportedRadix { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty input"),
+            ParseError::MissingDigits { index } => {
+                write!(f, "expected digit at index {index}, found end of input")
+            }
+            ParseError::InvalidDigit { index, found } => {
+                write!(f, "invalid digit {found:?} at index {index}")
+            }
+            ParseError::TooManyFractionalDigits { expected, actual } => write!(
+                f,
+                "too many fractional digits: expected at most {expected}, found {actual}"
+            ),
+            ParseError::MissingFractionalDigits { index } => {
+                write!(f, "missing fractional digits at index {index}")
+            }
+            ParseError::TrailingCharacters { index } => {
+                write!(f, "unexpected trailing characters at index {index}")
+            }
+            ParseError::ExponentOutOfRange { index } => {
+                write!(
+                    f,
+                    "exponent at index {index} exceeds {MAX_EXPONENT} in magnitude"
+                )
+            }
+            ParseError::MisplacedGroupSeparator { index } => {
+                write!(f, "misplaced group separator at index {index}")
+            }
+            ParseError::UnsupportedRadix { radix } => write!(f, "unsupported radix {radix}"),
+            ParseError::OutOfRange => write!(f, "value is out of range for the target type"),
+            #[cfg(feature = "alloc")]
+            ParseError::Other { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Largest exponent magnitude accepted in scientific notation such as `1.5e3`.
+///
+/// Bounds the size of the value a short input can produce.
+pub const MAX_EXPONENT: u32 = 10_000;
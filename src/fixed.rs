@@ -0,0 +1,2835 @@
+//! Fixed-capacity integers and decimals whose limbs are stored inline.
+//!
+//! [`SafeIntN`] and [`SafeDecN`] follow the arithmetic of `SafeInt` and `SafeDec`, but a value
+//! holds at most `LIMBS` 64-bit words. They are `Copy`, and instead of growing, every operation
+//! whose result does not fit returns `None`.
+//!
+//! Nothing here needs an allocator. Arithmetic, comparisons, `Display` and parsing (including
+//! radix prefixes and exponents) work in place, and these are the only types built when the
+//! `alloc` feature is off. [`SafeIntN::encode_into`] and [`SafeIntN::decode_from`] read and
+//! write the `SafeInt` lencode wire format in a byte slice, so firmware without an allocator
+//! exchanges the same bytes as a host using lencode.
+//!
+//! With `alloc`, the types also convert to and from `SafeInt` and `SafeDec` and implement
+//! lencode's `Encode`/`Decode`, writing every payload from the stack; only lencode's zstd step
+//! for payloads longer than 63 bytes (possible from `LIMBS = 8`) uses the heap. Those traits
+//! need `alloc` because lencode itself links the `alloc` crate.
+
+use crate::{
+    ConversionError, RoundingMode,
+    error::{MAX_EXPONENT, ParseError},
+    wire::{
+        LENCODE_MAX_VARINT_BYTES, LENCODE_SAFE_INT_PAYLOAD_MASK, LENCODE_SAFE_INT_SIZE_LARGE,
+        LENCODE_SAFE_INT_SIZE_MASK, LENCODE_SAFE_INT_SMALL_MAX, LENCODE_SAFE_INT_VARIANT_BYTES,
+        LENCODE_SAFE_INT_VARIANT_MASK, read_lencode_varint,
+    },
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    SafeDec, SafeInt,
+    integer::lencode_read_tag,
+    wire::{LENCODE_SAFE_INT_DEDUPE_MIN_BYTES, LENCODE_SAFE_INT_VARIANT_DEDUPE},
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Write as _},
+    ops::*,
+    str::FromStr,
+};
+#[cfg(all(test, feature = "alloc"))]
+use lencode::io::Cursor;
+#[cfg(feature = "alloc")]
+use lencode::{
+    Decode, Encode,
+    dedupe::{DedupeDecoder, DedupeEncoder},
+    io::{Error, Read, Write},
+    pack::Pack,
+};
+#[cfg(feature = "alloc")]
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(all(test, feature = "alloc"))]
+use std::format;
+#[cfg(test)]
+use std::string::ToString;
+
+/// Largest power of ten that fits in a limb, used to move decimal digits in chunks.
+const CHUNK: u64 = 10_000_000_000_000_000_000;
+/// Number of decimal digits in [`CHUNK`].
+const CHUNK_DIGITS: usize = 19;
+
+/// Signed integer with room for `LIMBS` 64-bit words, stored inline.
+///
+/// Values are kept as a sign and a magnitude below `2^(64 * LIMBS)`, so the range is symmetric
+/// and negation never fails. The operators match `SafeInt`'s (bitwise operators act on the
+/// infinite two's complement form, division truncates toward zero), but every binary operator
+/// returns `Option` and yields `None` when the result does not fit, or on division by zero. A
+/// primitive operand that does not fit on its own (a large `u128` or `i128` with `LIMBS = 1`)
+/// also yields `None`. The compound assignment operators leave the value unchanged in those
+/// cases.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::SafeIntN;
+///
+/// let a = SafeIntN::<2>::from(u64::MAX);
+/// let b = (a * a).unwrap();
+/// assert_eq!(b.to_string(), "340282366920938463426481119284349108225");
+/// assert_eq!(b * 2u8, None);
+/// assert_eq!("-0xff".parse::<SafeIntN<2>>().unwrap(), -255);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SafeIntN<const LIMBS: usize> {
+    /// Whether the value is below zero; never set for zero.
+    negative: bool,
+    /// Little-endian limbs of the absolute value.
+    magnitude: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> SafeIntN<LIMBS> {
+    /// Rejects `SafeIntN<0>` at compile time.
+    const HAS_LIMBS: () = assert!(LIMBS > 0, "SafeIntN needs at least one limb");
+
+    /// Largest representable value, `2^(64 * LIMBS) - 1`.
+    pub const MAX: SafeIntN<LIMBS> = SafeIntN {
+        negative: false,
+        magnitude: [u64::MAX; LIMBS],
+    };
+
+    /// Smallest representable value, `-(2^(64 * LIMBS) - 1)`.
+    pub const MIN: SafeIntN<LIMBS> = SafeIntN {
+        negative: true,
+        magnitude: [u64::MAX; LIMBS],
+    };
+
+    /// Zero value.
+    #[inline(always)]
+    pub const fn zero() -> SafeIntN<LIMBS> {
+        SafeIntN {
+            negative: false,
+            magnitude: [0; LIMBS],
+        }
+    }
+
+    /// One value.
+    #[inline(always)]
+    pub const fn one() -> SafeIntN<LIMBS> {
+        SafeIntN::from_u64(false, 1)
+    }
+
+    /// Builds `±value`, normalizing the sign of zero.
+    #[inline(always)]
+    const fn from_u64(negative: bool, value: u64) -> SafeIntN<LIMBS> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::HAS_LIMBS;
+        let mut magnitude = [0; LIMBS];
+        magnitude[0] = value;
+        SafeIntN {
+            negative: negative && value != 0,
+            magnitude,
+        }
+    }
+
+    /// Builds `±value`, or `None` if it does not fit.
+    #[inline(always)]
+    fn from_u128(negative: bool, value: u128) -> Option<SafeIntN<LIMBS>> {
+        let mut result = SafeIntN::from_u64(negative, value as u64);
+        let high = (value >> 64) as u64;
+        if high != 0 {
+            *result.magnitude.get_mut(1)? = high;
+            result.negative = negative;
+        }
+        Some(result)
+    }
+
+    /// Builds a value from a sign and magnitude, normalizing the sign of zero.
+    #[inline(always)]
+    fn from_parts(negative: bool, magnitude: [u64; LIMBS]) -> SafeIntN<LIMBS> {
+        SafeIntN {
+            negative: negative && !limbs_are_zero(&magnitude),
+            magnitude,
+        }
+    }
+
+    /// Returns `true` if the value is below zero.
+    #[inline(always)]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the value is zero.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        limbs_are_zero(&self.magnitude)
+    }
+
+    /// Absolute value; unlike the primitive integers this cannot overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeIntN;
+    ///
+    /// assert_eq!(SafeIntN::<1>::MIN.abs(), SafeIntN::<1>::MAX);
+    /// ```
+    #[inline(always)]
+    pub const fn abs(&self) -> SafeIntN<LIMBS> {
+        SafeIntN {
+            negative: false,
+            magnitude: self.magnitude,
+        }
+    }
+
+    /// Raises the number to an unsigned integer power, or returns `None` if the result does not
+    /// fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeIntN;
+    ///
+    /// assert_eq!(SafeIntN::<1>::from(-3).pow(3).unwrap(), -27);
+    /// assert_eq!(SafeIntN::<1>::from(2).pow(64), None);
+    /// ```
+    pub fn pow(self, mut exp: u32) -> Option<SafeIntN<LIMBS>> {
+        let mut base = self;
+        let mut result = SafeIntN::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.try_mul(base)?;
+            }
+            exp >>= 1;
+            // Squaring only overflows when `|base| >= 2` and a later bit still needs it.
+            if exp > 0 {
+                base = base.try_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Computes quotient and remainder simultaneously, truncating toward zero.
+    /// Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn div_rem(self, other: SafeIntN<LIMBS>) -> Option<(SafeIntN<LIMBS>, SafeIntN<LIMBS>)> {
+        self.try_div_rem(other)
+    }
+
+    /// Divides by `other`, rounding the quotient with the given [`RoundingMode`].
+    /// Returns `None` if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::{RoundingMode, SafeIntN};
+    ///
+    /// let seven = SafeIntN::<1>::from(7);
+    /// let two = SafeIntN::<1>::from(2);
+    /// assert_eq!(seven.div_round(&two, RoundingMode::TowardZero).unwrap(), 3);
+    /// assert_eq!(seven.div_round(&two, RoundingMode::HalfEven).unwrap(), 4);
+    /// assert_eq!((-seven).div_round(&two, RoundingMode::Floor).unwrap(), -4);
+    /// ```
+    pub fn div_round(
+        &self,
+        other: &SafeIntN<LIMBS>,
+        mode: RoundingMode,
+    ) -> Option<SafeIntN<LIMBS>> {
+        let (quotient, remainder) = self.try_div_rem(*other)?;
+        if remainder.is_zero() {
+            return Some(quotient);
+        }
+        let negative = self.negative != other.negative;
+        // Twice the remainder exceeds the divisor whenever the doubling carries out.
+        let half = || {
+            let mut doubled = remainder.magnitude;
+            match add_limbs(&mut doubled, &remainder.magnitude) {
+                true => Ordering::Greater,
+                false => cmp_limbs(&doubled, &other.magnitude),
+            }
+        };
+        if !mode.rounds_away(negative, half, quotient.magnitude[0] & 1 == 1) {
+            return Some(quotient);
+        }
+        // A non-zero remainder means `|other| >= 2`, so `|quotient| + 1` still fits.
+        let mut magnitude = quotient.magnitude;
+        add_limbs(&mut magnitude, &SafeIntN::<LIMBS>::one().magnitude);
+        Some(SafeIntN::from_parts(negative, magnitude))
+    }
+
+    /// Returns the absolute value as a `u128`, if it fits.
+    #[inline(always)]
+    fn magnitude_u128(&self) -> Option<u128> {
+        if self.magnitude.iter().skip(2).any(|&limb| limb != 0) {
+            return None;
+        }
+        let high = self.magnitude.get(1).copied().unwrap_or(0);
+        Some((u128::from(high) << 64) | u128::from(self.magnitude[0]))
+    }
+
+    #[inline(always)]
+    fn try_add(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        let mut magnitude = self.magnitude;
+        if self.negative == other.negative {
+            if add_limbs(&mut magnitude, &other.magnitude) {
+                return None;
+            }
+            return Some(SafeIntN::from_parts(self.negative, magnitude));
+        }
+        match cmp_limbs(&self.magnitude, &other.magnitude) {
+            Ordering::Less => {
+                let mut magnitude = other.magnitude;
+                sub_limbs(&mut magnitude, &self.magnitude);
+                Some(SafeIntN::from_parts(other.negative, magnitude))
+            }
+            _ => {
+                sub_limbs(&mut magnitude, &other.magnitude);
+                Some(SafeIntN::from_parts(self.negative, magnitude))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn try_sub(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.try_add(-other)
+    }
+
+    #[inline(always)]
+    fn try_mul(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        let mut low = [0; LIMBS];
+        let mut high = [0; LIMBS];
+        mul_wide(&self.magnitude, &other.magnitude, &mut low, &mut high);
+        if !limbs_are_zero(&high) {
+            return None;
+        }
+        Some(SafeIntN::from_parts(self.negative != other.negative, low))
+    }
+
+    /// Truncating division; the remainder takes the sign of `self`.
+    #[inline(always)]
+    fn try_div_rem(self, other: SafeIntN<LIMBS>) -> Option<(SafeIntN<LIMBS>, SafeIntN<LIMBS>)> {
+        if other.is_zero() {
+            return None;
+        }
+        let mut quotient = [0; LIMBS];
+        let mut remainder = [0; LIMBS];
+        div_wide(
+            &mut remainder,
+            &self.magnitude,
+            &other.magnitude,
+            &mut quotient,
+        );
+        Some((
+            SafeIntN::from_parts(self.negative != other.negative, quotient),
+            SafeIntN::from_parts(self.negative, remainder),
+        ))
+    }
+
+    #[inline(always)]
+    fn try_div(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.try_div_rem(other).map(|(quotient, _)| quotient)
+    }
+
+    #[inline(always)]
+    fn try_rem(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.try_div_rem(other).map(|(_, remainder)| remainder)
+    }
+
+    /// Returns the two's complement limbs of the value and the limb that sign-extends them.
+    #[inline(always)]
+    fn twos_complement(&self) -> ([u64; LIMBS], u64) {
+        if !self.negative {
+            return (self.magnitude, 0);
+        }
+        let mut limbs = self.magnitude;
+        negate_limbs(&mut limbs);
+        (limbs, u64::MAX)
+    }
+
+    /// Applies a bitwise operation to both two's complement forms.
+    #[inline(always)]
+    fn bitwise(
+        self,
+        other: SafeIntN<LIMBS>,
+        op: impl Fn(u64, u64) -> u64,
+    ) -> Option<SafeIntN<LIMBS>> {
+        let (mut limbs, extension) = self.twos_complement();
+        let (other_limbs, other_extension) = other.twos_complement();
+        for (limb, other) in limbs.iter_mut().zip(other_limbs) {
+            *limb = op(*limb, other);
+        }
+        if op(extension, other_extension) == 0 {
+            return Some(SafeIntN::from_parts(false, limbs));
+        }
+        // The magnitude of a negative result is its two's complement; `-2^(64 * LIMBS)` is the
+        // one negative value whose magnitude needs an extra limb.
+        if limbs_are_zero(&limbs) {
+            return None;
+        }
+        negate_limbs(&mut limbs);
+        Some(SafeIntN::from_parts(true, limbs))
+    }
+
+    #[inline(always)]
+    fn try_bitand(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.bitwise(other, |a, b| a & b)
+    }
+
+    #[inline(always)]
+    fn try_bitor(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.bitwise(other, |a, b| a | b)
+    }
+
+    #[inline(always)]
+    fn try_bitxor(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+        self.bitwise(other, |a, b| a ^ b)
+    }
+
+    /// Orders `self` against an operand that is `None` when it is too large to represent, in
+    /// which case only its sign matters.
+    #[inline(always)]
+    fn cmp_operand(&self, other: Option<SafeIntN<LIMBS>>, other_negative: bool) -> Ordering {
+        match other {
+            Some(other) => self.cmp(&other),
+            None if other_negative => Ordering::Greater,
+            None => Ordering::Less,
+        }
+    }
+
+    /// Returns byte `index` of the zigzag encoding `2 * |v| - (v < 0)`, which equals
+    /// `(|v| - (v < 0)) << 1 | (v < 0)`.
+    #[inline(always)]
+    fn zigzag_byte(base: &[u64; LIMBS], negative: bool, index: usize) -> u8 {
+        let limb = index / 8;
+        let word = match limb {
+            0 => (base[0] << 1) | u64::from(negative),
+            _ if limb < LIMBS => (base[limb] << 1) | (base[limb - 1] >> 63),
+            _ if limb == LIMBS => base[LIMBS - 1] >> 63,
+            _ => 0,
+        };
+        (word >> (8 * (index % 8))) as u8
+    }
+
+    /// Returns `|v| - (v < 0)` and the byte length of the zigzag encoding.
+    #[inline(always)]
+    fn zigzag_base(&self) -> ([u64; LIMBS], usize) {
+        let mut base = self.magnitude;
+        if self.negative {
+            decrement_limbs(&mut base);
+        }
+        let bits = 64 * LIMBS - leading_zeros(&base) + usize::from(!limbs_are_zero(&base));
+        let bits = bits.max(usize::from(self.negative));
+        (base, bits.div_ceil(8))
+    }
+
+    /// Decodes a little-endian zigzag payload, or `None` if the value does not fit.
+    fn from_zigzag_bytes(bytes: &[u8]) -> Option<SafeIntN<LIMBS>> {
+        let negative = bytes.first().is_some_and(|byte| byte & 1 == 1);
+        let mut base = [0; LIMBS];
+        for (index, &byte) in bytes.iter().enumerate() {
+            // Bit `8 * index + k` of the payload is bit `8 * index + k - 1` of the base.
+            let bit = 8 * index;
+            let value = u64::from(byte);
+            if bit > 0 {
+                let target = (bit - 1) / 64;
+                let shift = (bit - 1) % 64;
+                if target < LIMBS {
+                    base[target] |= value << shift;
+                } else if value != 0 {
+                    return None;
+                }
+                if shift > 56 {
+                    let spill = value >> (64 - shift);
+                    match base.get_mut(target + 1) {
+                        Some(limb) => *limb |= spill,
+                        None if spill != 0 => return None,
+                        None => {}
+                    }
+                }
+            } else {
+                base[0] |= value >> 1;
+            }
+        }
+        if negative && add_limbs(&mut base, &SafeIntN::<LIMBS>::one().magnitude) {
+            return None;
+        }
+        Some(SafeIntN::from_parts(negative, base))
+    }
+}
+
+impl<const LIMBS: usize> SafeIntN<LIMBS> {
+    /// Writes the value to the start of `buf` in the `SafeInt` lencode wire format, without an
+    /// allocator, and returns the number of bytes written.
+    ///
+    /// The output matches `SafeInt`'s `Encode` byte for byte: the zigzag payload follows a
+    /// one-byte header, or is the header itself when it is at most 63. Returns `None` if `buf`
+    /// is too short, or if the payload is longer than 63 bytes (possible from `LIMBS = 8`),
+    /// since lencode then decides between raw and zstd-compressed bytes by compressing them.
+    /// `buf` needs at most `8 * LIMBS + 2` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeIntN;
+    ///
+    /// let mut buf = [0u8; 18];
+    /// let len = SafeIntN::<2>::from(-1000).encode_into(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], &[0x42, 0xcf, 0x07]);
+    /// assert_eq!(SafeIntN::<2>::decode_from(&buf[..len]), Some((SafeIntN::from(-1000), len)));
+    /// ```
+    pub fn encode_into(&self, buf: &mut [u8]) -> Option<usize> {
+        let (base, len) = self.zigzag_base();
+        if len > LENCODE_MAX_VARINT_BYTES {
+            return None;
+        }
+        let first = SafeIntN::zigzag_byte(&base, self.negative, 0);
+        if len <= 1 && first <= LENCODE_SAFE_INT_SMALL_MAX {
+            *buf.first_mut()? = first;
+            return Some(1);
+        }
+        let (header, payload) = buf.get_mut(..=len)?.split_first_mut()?;
+        *header = LENCODE_SAFE_INT_SIZE_LARGE | len as u8;
+        for (index, byte) in payload.iter_mut().enumerate() {
+            *byte = SafeIntN::zigzag_byte(&base, self.negative, index);
+        }
+        Some(len + 1)
+    }
+
+    /// Reads a value in the `SafeInt` lencode wire format from the start of `bytes`, without
+    /// an allocator, returning it with the number of bytes read.
+    ///
+    /// Accepts everything [`SafeIntN::encode_into`] writes, and lencode's raw byte-slice form
+    /// (header `0x80`) that `SafeInt` uses for payloads longer than 63 bytes. Returns `None`
+    /// if `bytes` is truncated, the value does not fit, or the payload is zstd-compressed or a
+    /// dedupe reference, which need lencode to decode.
+    pub fn decode_from(bytes: &[u8]) -> Option<(SafeIntN<LIMBS>, usize)> {
+        let (&tag, rest) = bytes.split_first()?;
+        if tag & LENCODE_SAFE_INT_VARIANT_MASK != 0 {
+            if tag != LENCODE_SAFE_INT_VARIANT_BYTES {
+                return None;
+            }
+            // lencode's byte slices start with a varint of `payload_len << 1 | compressed`.
+            let (flagged, header) = read_lencode_varint(rest)?;
+            if flagged & 1 == 1 {
+                return None;
+            }
+            let len = usize::try_from(flagged >> 1).ok()?;
+            let payload = rest.get(header..header.checked_add(len)?)?;
+            return Some((SafeIntN::from_zigzag_bytes(payload)?, 1 + header + len));
+        }
+        if tag & LENCODE_SAFE_INT_SIZE_MASK == 0 {
+            let value = SafeIntN::from_zigzag_bytes(&[tag & LENCODE_SAFE_INT_PAYLOAD_MASK])?;
+            return Some((value, 1));
+        }
+        let len = usize::from(tag & LENCODE_SAFE_INT_PAYLOAD_MASK);
+        if len == 0 {
+            return None;
+        }
+        let value = SafeIntN::from_zigzag_bytes(rest.get(..len)?)?;
+        Some((value, 1 + len))
+    }
+}
+
+impl<const LIMBS: usize> Default for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn default() -> Self {
+        SafeIntN::zero()
+    }
+}
+
+impl<const LIMBS: usize> Ord for SafeIntN<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_limbs(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_limbs(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for SafeIntN<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SafeIntN({self})")
+    }
+}
+
+/// Honors width, fill, alignment, `+` and `0` flags. Like the primitive integers, the
+/// precision is ignored.
+impl<const LIMBS: usize> Display for SafeIntN<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = digit_count(self.magnitude);
+        pad_number(f, !self.negative, len, |f| {
+            for_each_digit(self.magnitude, &mut |digit| f.write_char(digit))
+        })
+    }
+}
+
+/// Accepts the same syntax as `SafeInt`'s parser (an optional `-`, a `0x`/`0o`/`0b` prefix or
+/// a decimal literal with an optional exponent) and reports the same errors, but parses in
+/// place. Values that do not fit fail with [`ParseError::OutOfRange`].
+impl<const LIMBS: usize> FromStr for SafeIntN<LIMBS> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut literal = Literal::new(s)?;
+        let negative = literal.eat('-');
+        let radix = literal.radix_prefix();
+        let mut mantissa = Mantissa::<LIMBS>::new(radix);
+        literal.digits(radix, |digit| mantissa.push(digit))?;
+        let mut fraction_len = 0;
+        if radix == 10 && literal.peek() == Some('.') {
+            // A fractional part is only allowed in exponent form, where it can still be
+            // integral.
+            let mut rest = literal;
+            let mut extended = mantissa;
+            rest.position += 1;
+            if let Ok(len) = rest.digits(10, |digit| extended.push(digit))
+                && matches!(rest.peek(), Some('e' | 'E'))
+            {
+                fraction_len = len;
+                (literal, mantissa) = (rest, extended);
+            }
+        }
+        let exponent = literal.exponent()?.unwrap_or(0);
+        literal.end()?;
+        match mantissa.finish(exponent - fraction_len as i64) {
+            Ok(Some(magnitude)) => Ok(SafeIntN::from_parts(negative, magnitude)),
+            Ok(None) => Err(ParseError::OutOfRange),
+            Err(excess) => Err(ParseError::TooManyFractionalDigits {
+                expected: 0,
+                actual: excess,
+            }),
+        }
+    }
+}
+
+impl<const LIMBS: usize> Neg for SafeIntN<LIMBS> {
+    type Output = SafeIntN<LIMBS>;
+
+    #[inline(always)]
+    fn neg(self) -> SafeIntN<LIMBS> {
+        SafeIntN::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl<const LIMBS: usize> Neg for &SafeIntN<LIMBS> {
+    type Output = SafeIntN<LIMBS>;
+
+    #[inline(always)]
+    fn neg(self) -> SafeIntN<LIMBS> {
+        -*self
+    }
+}
+
+macro_rules! impl_fixed_ops {
+    ($($trait:ident, $method:ident, $op:ident);* $(;)?) => {
+        $(
+            impl<const LIMBS: usize> $trait for SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(other)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<&SafeIntN<LIMBS>> for SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(*other)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<SafeIntN<LIMBS>> for &SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(other)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<&SafeIntN<LIMBS>> for &SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(*other)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_ops!(
+    Add, add, try_add;
+    Sub, sub, try_sub;
+    Mul, mul, try_mul;
+    Div, div, try_div;
+    Rem, rem, try_rem;
+    BitAnd, bitand, try_bitand;
+    BitOr, bitor, try_bitor;
+    BitXor, bitxor, try_bitxor;
+);
+
+/// Primitive integers accepted as operands of the fixed-capacity types.
+trait Primitive: Copy {
+    /// Converts the value, or returns `None` if it needs more than `LIMBS` words.
+    fn to_fixed<const LIMBS: usize>(self) -> Option<SafeIntN<LIMBS>>;
+
+    /// Returns `true` if the value is below zero.
+    fn is_below_zero(self) -> bool;
+}
+
+macro_rules! impl_primitive {
+    (unsigned: $($prim:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl Primitive for $prim {
+                #[inline(always)]
+                fn to_fixed<const LIMBS: usize>(self) -> Option<SafeIntN<LIMBS>> {
+                    SafeIntN::from_u128(false, self as u128)
+                }
+
+                #[inline(always)]
+                fn is_below_zero(self) -> bool {
+                    false
+                }
+            }
+        )*
+        $(
+            impl Primitive for $signed {
+                #[inline(always)]
+                fn to_fixed<const LIMBS: usize>(self) -> Option<SafeIntN<LIMBS>> {
+                    SafeIntN::from_u128(self < 0, (self as i128).unsigned_abs())
+                }
+
+                #[inline(always)]
+                fn is_below_zero(self) -> bool {
+                    self < 0
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_fixed_prim_ops {
+    ($trait:ident, $method:ident, $op:ident, [$($prim:ty),* $(,)?]) => {
+        $(
+            impl<const LIMBS: usize> $trait<$prim> for SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(other.to_fixed()?)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<$prim> for &SafeIntN<LIMBS> {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeIntN<LIMBS>> {
+                    self.$op(other.to_fixed()?)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<SafeIntN<LIMBS>> for $prim {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.to_fixed::<LIMBS>()?.$op(other)
+                }
+            }
+
+            impl<const LIMBS: usize> $trait<&SafeIntN<LIMBS>> for $prim {
+                type Output = Option<SafeIntN<LIMBS>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeIntN<LIMBS>) -> Option<SafeIntN<LIMBS>> {
+                    self.to_fixed::<LIMBS>()?.$op(*other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! for_each_fixed_op {
+    ($macro:ident, [$($prim:ty),* $(,)?]) => {
+        $macro!(Add, add, try_add, [$($prim),*]);
+        $macro!(Sub, sub, try_sub, [$($prim),*]);
+        $macro!(Mul, mul, try_mul, [$($prim),*]);
+        $macro!(Div, div, try_div, [$($prim),*]);
+        $macro!(Rem, rem, try_rem, [$($prim),*]);
+        $macro!(BitAnd, bitand, try_bitand, [$($prim),*]);
+        $macro!(BitOr, bitor, try_bitor, [$($prim),*]);
+        $macro!(BitXor, bitxor, try_bitxor, [$($prim),*]);
+    };
+}
+
+for_each_fixed_op!(
+    impl_fixed_prim_ops,
+    [
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+    ]
+);
+
+/// Implements the compound assignment operators on top of the binary ones for every operand
+/// those accept. A `None` result (overflow or a zero divisor) leaves `self` unchanged, matching
+/// `SafeDec`'s `DivAssign`; use the binary operator directly to detect it.
+macro_rules! impl_fixed_assign_ops {
+    ($generics:tt $ty:ty; $($trait:ident, $method:ident, $op:ident, $op_method:ident);* $(;)?) => {
+        $(
+            impl_fixed_assign_ops!(@impl $generics $ty, $trait, $method, $op, $op_method);
+        )*
+    };
+    (@impl [$($generics:tt)*] $ty:ty, $trait:ident, $method:ident, $op:ident, $op_method:ident) => {
+        impl<$($generics)*, O> $trait<O> for $ty
+        where
+            $ty: $op<O, Output = Option<$ty>>,
+        {
+            #[inline(always)]
+            fn $method(&mut self, rhs: O) {
+                if let Some(result) = (*self).$op_method(rhs) {
+                    *self = result;
+                }
+            }
+        }
+    };
+}
+
+impl_fixed_assign_ops!(
+    [const LIMBS: usize] SafeIntN<LIMBS>;
+    AddAssign, add_assign, Add, add;
+    SubAssign, sub_assign, Sub, sub;
+    MulAssign, mul_assign, Mul, mul;
+    DivAssign, div_assign, Div, div;
+    RemAssign, rem_assign, Rem, rem;
+    BitAndAssign, bitand_assign, BitAnd, bitand;
+    BitOrAssign, bitor_assign, BitOr, bitor;
+    BitXorAssign, bitxor_assign, BitXor, bitxor;
+);
+
+macro_rules! impl_fixed_cmp_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl<const LIMBS: usize> PartialEq<$prim> for SafeIntN<LIMBS> {
+                #[inline(always)]
+                fn eq(&self, other: &$prim) -> bool {
+                    other.to_fixed() == Some(*self)
+                }
+            }
+
+            impl<const LIMBS: usize> PartialOrd<$prim> for SafeIntN<LIMBS> {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    Some(self.cmp_operand(other.to_fixed(), other.is_below_zero()))
+                }
+            }
+
+            impl<const LIMBS: usize> PartialEq<SafeIntN<LIMBS>> for $prim {
+                #[inline(always)]
+                fn eq(&self, other: &SafeIntN<LIMBS>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<const LIMBS: usize> PartialOrd<SafeIntN<LIMBS>> for $prim {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeIntN<LIMBS>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_cmp_for_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_fixed_from_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl<const LIMBS: usize> From<$prim> for SafeIntN<LIMBS> {
+                #[inline(always)]
+                fn from(value: $prim) -> SafeIntN<LIMBS> {
+                    let magnitude = (value as i128).unsigned_abs() as u64;
+                    SafeIntN::from_u64((value as i128) < 0, magnitude)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_from_primitive!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_fixed_try_from_wide_primitive {
+    ($($prim:ty),*) => {
+        $(
+            /// Fails when `LIMBS = 1` and the value needs more than 64 bits.
+            impl<const LIMBS: usize> TryFrom<$prim> for SafeIntN<LIMBS> {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: $prim) -> Result<SafeIntN<LIMBS>, ConversionError> {
+                    value.to_fixed().ok_or(if value.is_below_zero() {
+                        ConversionError::Underflow { target: "SafeIntN" }
+                    } else {
+                        ConversionError::Overflow { target: "SafeIntN" }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_try_from_wide_primitive!(u128, i128);
+
+macro_rules! impl_primitive_try_from_fixed {
+    ($($prim:ident),*) => {
+        $(
+            impl<const LIMBS: usize> TryFrom<SafeIntN<LIMBS>> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeIntN<LIMBS>) -> Result<$prim, ConversionError> {
+                    let error = if value.negative {
+                        ConversionError::Underflow {
+                            target: stringify!($prim),
+                        }
+                    } else {
+                        ConversionError::Overflow {
+                            target: stringify!($prim),
+                        }
+                    };
+                    let magnitude = value.magnitude_u128().ok_or(error)?;
+                    if value.negative {
+                        let signed = 0i128.checked_sub_unsigned(magnitude).ok_or(error)?;
+                        $prim::try_from(signed).map_err(|_| error)
+                    } else {
+                        $prim::try_from(magnitude).map_err(|_| error)
+                    }
+                }
+            }
+
+            impl<const LIMBS: usize> TryFrom<&SafeIntN<LIMBS>> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeIntN<LIMBS>) -> Result<$prim, ConversionError> {
+                    $prim::try_from(*value)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_try_from_fixed!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Widens to a [`SafeInt`], which always succeeds.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{SafeInt, SafeIntN};
+///
+/// let a = SafeIntN::<2>::from(u64::MAX);
+/// let product = SafeInt::from(u64::MAX) * SafeInt::from(u64::MAX);
+/// assert_eq!(SafeInt::from((a * a).unwrap()), product);
+/// assert_eq!(SafeIntN::<2>::try_from(&product), Ok((a * a).unwrap()));
+/// ```
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> From<SafeIntN<LIMBS>> for SafeInt {
+    fn from(value: SafeIntN<LIMBS>) -> SafeInt {
+        if let Some(magnitude) = value.magnitude_u128()
+            && let Some(small) = 0i128.checked_add_unsigned(magnitude)
+        {
+            return SafeInt::from(if value.negative { -small } else { small });
+        }
+        let digits: Vec<u32> = value
+            .magnitude
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect();
+        let sign = if value.negative {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        SafeInt::from_big(BigInt::from_biguint(sign, BigUint::new(digits)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> From<&SafeIntN<LIMBS>> for SafeInt {
+    #[inline(always)]
+    fn from(value: &SafeIntN<LIMBS>) -> SafeInt {
+        SafeInt::from(*value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> TryFrom<&SafeInt> for SafeIntN<LIMBS> {
+    type Error = ConversionError;
+
+    fn try_from(value: &SafeInt) -> Result<SafeIntN<LIMBS>, ConversionError> {
+        let error = if value.is_negative() {
+            ConversionError::Underflow { target: "SafeIntN" }
+        } else {
+            ConversionError::Overflow { target: "SafeIntN" }
+        };
+        if let Some(small) = value.as_small() {
+            return SafeIntN::from_u128(small < 0, small.unsigned_abs()).ok_or(error);
+        }
+        let digits = value.big().magnitude().to_u64_digits();
+        if digits.len() > LIMBS {
+            return Err(error);
+        }
+        let mut magnitude = [0; LIMBS];
+        magnitude[..digits.len()].copy_from_slice(&digits);
+        Ok(SafeIntN::from_parts(value.is_negative(), magnitude))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> TryFrom<SafeInt> for SafeIntN<LIMBS> {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: SafeInt) -> Result<SafeIntN<LIMBS>, ConversionError> {
+        SafeIntN::try_from(&value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> PartialEq<SafeInt> for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn eq(&self, other: &SafeInt) -> bool {
+        SafeIntN::try_from(other).is_ok_and(|other| *self == other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> PartialOrd<SafeInt> for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeInt) -> Option<Ordering> {
+        Some(self.cmp_operand(SafeIntN::try_from(other).ok(), other.is_negative()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> PartialEq<SafeIntN<LIMBS>> for SafeInt {
+    #[inline(always)]
+    fn eq(&self, other: &SafeIntN<LIMBS>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> PartialOrd<SafeIntN<LIMBS>> for SafeInt {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeIntN<LIMBS>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Same wire format as [`SafeInt`]'s plain encoding, written from a stack buffer. Payloads
+/// longer than 63 bytes use lencode's byte-slice encoding, which may zstd-compress them.
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> Pack for SafeIntN<LIMBS> {
+    fn pack(&self, writer: &mut impl Write) -> lencode::Result<usize> {
+        let mut header = [0u8; 1 + LENCODE_MAX_VARINT_BYTES];
+        if let Some(len) = self.encode_into(&mut header) {
+            return writer.write(&header[..len]);
+        }
+        let (base, len) = self.zigzag_base();
+        let mut buffer: ZigzagBuffer<LIMBS> = [[[0; 8]; LIMBS]; 2];
+        let bytes = &mut buffer.as_flattened_mut().as_flattened_mut()[..len];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = SafeIntN::zigzag_byte(&base, self.negative, index);
+        }
+        let bytes: &[u8] = bytes;
+        let mut total = writer.write(&[LENCODE_SAFE_INT_VARIANT_BYTES])?;
+        total += bytes.encode(writer)?;
+        Ok(total)
+    }
+
+    fn unpack(reader: &mut impl Read) -> lencode::Result<Self> {
+        let tag = lencode_read_tag(reader)?;
+        SafeIntN::unpack_from_tag(tag, reader)
+    }
+}
+
+/// Room for the longest zigzag payload, `8 * LIMBS + 1` bytes, as nested arrays because the
+/// length cannot be computed from `LIMBS` on stable Rust.
+#[cfg(feature = "alloc")]
+type ZigzagBuffer<const LIMBS: usize> = [[[u8; 8]; LIMBS]; 2];
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> SafeIntN<LIMBS> {
+    /// Decodes the payload following `tag`, failing with [`Error::InvalidData`] if the value
+    /// does not fit.
+    fn unpack_from_tag(tag: u8, reader: &mut impl Read) -> lencode::Result<Self> {
+        if tag & LENCODE_SAFE_INT_VARIANT_MASK != 0 {
+            if tag == LENCODE_SAFE_INT_VARIANT_DEDUPE {
+                return Err(Error::InvalidData);
+            }
+            return SafeIntN::unpack_bytes(reader);
+        }
+        if tag & LENCODE_SAFE_INT_SIZE_MASK == 0 {
+            return SafeIntN::from_zigzag_bytes(&[tag & LENCODE_SAFE_INT_PAYLOAD_MASK])
+                .ok_or(Error::InvalidData);
+        }
+        let len = (tag & LENCODE_SAFE_INT_PAYLOAD_MASK) as usize;
+        if len == 0 {
+            return Err(Error::InvalidData);
+        }
+        let mut bytes = [0u8; LENCODE_MAX_VARINT_BYTES];
+        read_exact(reader, &mut bytes[..len])?;
+        SafeIntN::from_zigzag_bytes(&bytes[..len]).ok_or(Error::InvalidData)
+    }
+
+    /// Decodes lencode's byte-slice encoding: a varint of `payload_len << 1 | compressed`
+    /// followed by the payload. Raw payloads are read onto the stack, and any bytes past the
+    /// largest value must be zero; compressed ones are inflated by lencode.
+    fn unpack_bytes(reader: &mut impl Read) -> lencode::Result<Self> {
+        let flagged = Vec::<u8>::decode_len(reader)?;
+        let len = flagged >> 1;
+        if flagged & 1 == 1 {
+            // zstd needs the whole frame, so hand the header back to lencode's own decoder.
+            let mut header = Vec::new();
+            Vec::<u8>::encode_len(flagged, &mut header)?;
+            let bytes: Vec<u8> = Vec::decode(&mut Prefixed {
+                prefix: &header,
+                reader,
+            })?;
+            return SafeIntN::from_zigzag_bytes(&bytes).ok_or(Error::InvalidData);
+        }
+        let mut buffer: ZigzagBuffer<LIMBS> = [[[0; 8]; LIMBS]; 2];
+        let bytes = buffer.as_flattened_mut().as_flattened_mut();
+        let kept = len.min(bytes.len());
+        read_exact(reader, &mut bytes[..kept])?;
+        let mut excess = len - kept;
+        let mut scratch = [0u8; 64];
+        while excess > 0 {
+            let step = excess.min(scratch.len());
+            read_exact(reader, &mut scratch[..step])?;
+            if scratch[..step].iter().any(|&byte| byte != 0) {
+                return Err(Error::InvalidData);
+            }
+            excess -= step;
+        }
+        SafeIntN::from_zigzag_bytes(&bytes[..kept]).ok_or(Error::InvalidData)
+    }
+}
+
+/// Reader that yields `prefix` before the bytes of `reader`.
+#[cfg(feature = "alloc")]
+struct Prefixed<'a, R: Read> {
+    prefix: &'a [u8],
+    reader: &'a mut R,
+}
+
+#[cfg(feature = "alloc")]
+impl<R: Read> Read for Prefixed<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> lencode::Result<usize> {
+        if self.prefix.is_empty() {
+            return self.reader.read(buf);
+        }
+        let n = self.prefix.len().min(buf.len());
+        buf[..n].copy_from_slice(&self.prefix[..n]);
+        self.prefix = &self.prefix[n..];
+        Ok(n)
+    }
+}
+
+/// Fills `buf` from `reader`, failing with [`Error::ReaderOutOfData`] if it runs out first.
+#[cfg(feature = "alloc")]
+fn read_exact(reader: &mut impl Read, buf: &mut [u8]) -> lencode::Result<()> {
+    let mut read = 0;
+    while read < buf.len() {
+        let n = reader.read(&mut buf[read..])?;
+        if n == 0 {
+            return Err(Error::ReaderOutOfData);
+        }
+        read += n;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> Encode for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn encode_ext(
+        &self,
+        writer: &mut impl Write,
+        dedupe_encoder: Option<&mut DedupeEncoder>,
+    ) -> lencode::Result<usize> {
+        if let Some(encoder) = dedupe_encoder
+            && self.zigzag_base().1 as u64 > LENCODE_SAFE_INT_DEDUPE_MIN_BYTES
+        {
+            let mut total = writer.write(&[LENCODE_SAFE_INT_VARIANT_DEDUPE])?;
+            total += encoder.encode(self, writer)?;
+            return Ok(total);
+        }
+        self.pack(writer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> Decode for SafeIntN<LIMBS> {
+    #[inline(always)]
+    fn decode_ext(
+        reader: &mut impl Read,
+        dedupe_decoder: Option<&mut DedupeDecoder>,
+    ) -> lencode::Result<Self> {
+        let tag = lencode_read_tag(reader)?;
+        if tag == LENCODE_SAFE_INT_VARIANT_DEDUPE
+            && let Some(decoder) = dedupe_decoder
+        {
+            return decoder.decode(reader);
+        }
+        SafeIntN::unpack_from_tag(tag, reader)
+    }
+}
+
+/// Fixed-point decimal with `D` fractional digits over a [`SafeIntN`] raw value.
+///
+/// The operators match `SafeDec`'s: multiplication and division keep `D` digits and
+/// truncate, remainders and bitwise operators act on the raw values, and a primitive operand
+/// of `+`, `-`, `%` or a bitwise operator is first scaled by `10^D`. Every binary operator
+/// returns `Option`, with `None` when a result or intermediate does not fit or on division by
+/// zero. Products are formed at double width, so `a * b` only fails if the truncated result
+/// itself does not fit.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::SafeDecN;
+///
+/// let price: SafeDecN<2, 2> = "12.50".parse().unwrap();
+/// let total = (price * 3u8).unwrap();
+/// assert_eq!(total.to_string(), "37.50");
+/// assert_eq!((total / price).unwrap().to_string(), "3.00");
+/// assert_eq!("3.75e1".parse::<SafeDecN<2, 2>>(), Ok(total));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SafeDecN<const LIMBS: usize, const D: usize>(SafeIntN<LIMBS>);
+
+impl<const LIMBS: usize, const D: usize> SafeDecN<LIMBS, D> {
+    /// Zero value at the given scale.
+    #[inline(always)]
+    pub const fn zero() -> SafeDecN<LIMBS, D> {
+        SafeDecN(SafeIntN::zero())
+    }
+
+    /// Creates a `SafeDecN` from an already scaled integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::{SafeDecN, SafeIntN};
+    ///
+    /// let dec = SafeDecN::<1, 3>::from_raw(SafeIntN::from(123_456));
+    /// assert_eq!(dec.to_string(), "123.456");
+    /// ```
+    #[inline(always)]
+    pub const fn from_raw(raw: SafeIntN<LIMBS>) -> Self {
+        SafeDecN(raw)
+    }
+
+    /// Returns the underlying integer scaled by `10^D`.
+    #[inline(always)]
+    pub const fn raw(&self) -> SafeIntN<LIMBS> {
+        self.0
+    }
+
+    /// Writes the value to the start of `buf` in the `SafeDec` lencode wire format, which is
+    /// the encoding of the scaled integer. See [`SafeIntN::encode_into`].
+    #[inline(always)]
+    pub fn encode_into(&self, buf: &mut [u8]) -> Option<usize> {
+        self.0.encode_into(buf)
+    }
+
+    /// Reads a value in the `SafeDec` lencode wire format from the start of `bytes`, returning
+    /// it with the number of bytes read. See [`SafeIntN::decode_from`].
+    #[inline(always)]
+    pub fn decode_from(bytes: &[u8]) -> Option<(SafeDecN<LIMBS, D>, usize)> {
+        SafeIntN::decode_from(bytes).map(|(raw, len)| (SafeDecN(raw), len))
+    }
+
+    /// Returns `value * 10^D`, or `None` if it does not fit.
+    fn scaled(value: SafeIntN<LIMBS>) -> Option<SafeDecN<LIMBS, D>> {
+        let mut magnitude = value.magnitude;
+        let mut remaining = D;
+        while remaining > 0 {
+            let step = remaining.min(CHUNK_DIGITS);
+            if mul_small(&mut magnitude, 10u64.pow(step as u32), 0) != 0 {
+                return None;
+            }
+            remaining -= step;
+        }
+        Some(SafeDecN(SafeIntN::from_parts(value.negative, magnitude)))
+    }
+
+    /// Returns `(self * other) / 10^D`, truncated toward zero.
+    fn try_mul(self, other: SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+        let mut low = [0; LIMBS];
+        let mut high = [0; LIMBS];
+        mul_wide(&self.0.magnitude, &other.0.magnitude, &mut low, &mut high);
+        let mut remaining = D;
+        while remaining > 0 {
+            let step = remaining.min(CHUNK_DIGITS);
+            let divisor = 10u64.pow(step as u32);
+            let carry = div_small(&mut high, divisor, 0);
+            div_small(&mut low, divisor, carry);
+            remaining -= step;
+        }
+        if !limbs_are_zero(&high) {
+            return None;
+        }
+        let negative = self.0.negative != other.0.negative;
+        Some(SafeDecN(SafeIntN::from_parts(negative, low)))
+    }
+
+    /// Returns `(self * 10^D) / other`, truncated toward zero.
+    fn try_div(self, other: SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+        if other.0.is_zero() {
+            return None;
+        }
+        let mut low = self.0.magnitude;
+        let mut high = [0; LIMBS];
+        let mut remaining = D;
+        while remaining > 0 {
+            let step = remaining.min(CHUNK_DIGITS);
+            let factor = 10u64.pow(step as u32);
+            let carry = mul_small(&mut low, factor, 0);
+            if mul_small(&mut high, factor, carry) != 0 {
+                return None;
+            }
+            remaining -= step;
+        }
+        // A quotient needing more than `LIMBS` words means `high >= other`.
+        if cmp_limbs(&high, &other.0.magnitude) != Ordering::Less {
+            return None;
+        }
+        let mut quotient = [0; LIMBS];
+        div_wide(&mut high, &low, &other.0.magnitude, &mut quotient);
+        let negative = self.0.negative != other.0.negative;
+        Some(SafeDecN(SafeIntN::from_parts(negative, quotient)))
+    }
+
+    /// Returns `|raw|` rounded half to even to `places` fractional digits when that drops
+    /// digits, together with the number of fractional digits it carries.
+    fn rounded_magnitude(&self, places: usize) -> ([u64; LIMBS], usize) {
+        let mut magnitude = self.0.magnitude;
+        if places >= D {
+            return (magnitude, D);
+        }
+        // Dividing by `10^(D - places)` in steps: all but the last digit only matter through
+        // whether they are non-zero, which settles ties.
+        let mut sticky = false;
+        let mut remaining = D - places - 1;
+        while remaining > 0 {
+            let step = remaining.min(CHUNK_DIGITS);
+            sticky |= div_small(&mut magnitude, 10u64.pow(step as u32), 0) != 0;
+            remaining -= step;
+        }
+        let last = div_small(&mut magnitude, 10, 0);
+        let odd = magnitude[0] & 1 == 1;
+        if last > 5 || (last == 5 && (sticky || odd)) {
+            // The quotient is at most `|raw| / 10`, so adding one cannot overflow.
+            add_limbs(&mut magnitude, &SafeIntN::<LIMBS>::one().magnitude);
+        }
+        (magnitude, places)
+    }
+}
+
+/// Accepts the same syntax as `SafeDec`'s strict parser (the [`Display`] form, optionally in
+/// scientific notation) and reports the same errors, but parses in place. Values that do not
+/// fit fail with [`ParseError::OutOfRange`].
+impl<const LIMBS: usize, const D: usize> FromStr for SafeDecN<LIMBS, D> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut literal = Literal::new(s)?;
+        let negative = literal.eat('-');
+        let mut mantissa = Mantissa::<LIMBS>::new(10);
+        literal.digits(10, |digit| mantissa.push(digit))?;
+        let has_point = match literal.peek() {
+            Some('.') => {
+                literal.position += 1;
+                true
+            }
+            Some('e' | 'E') => false,
+            Some(found) if found.is_ascii_alphanumeric() => {
+                return Err(ParseError::InvalidDigit {
+                    index: literal.position,
+                    found,
+                });
+            }
+            _ if D == 0 => false,
+            _ => {
+                return Err(ParseError::MissingFractionalDigits {
+                    index: literal.position,
+                });
+            }
+        };
+        let fraction_len = if has_point {
+            match literal.digits(10, |digit| mantissa.push(digit)) {
+                Ok(len) => len,
+                Err(ParseError::MissingDigits { index }) => {
+                    return Err(ParseError::MissingFractionalDigits { index });
+                }
+                Err(ParseError::InvalidDigit { index, found })
+                    if !found.is_ascii_alphanumeric() =>
+                {
+                    return Err(ParseError::MissingFractionalDigits { index });
+                }
+                Err(err) => return Err(err),
+            }
+        } else {
+            0
+        };
+        let exponent = literal.exponent()?;
+        literal.end()?;
+        if exponent.is_none() && fraction_len > D {
+            return Err(ParseError::TooManyFractionalDigits {
+                expected: D,
+                actual: fraction_len,
+            });
+        }
+        let shift = D as i64 + exponent.unwrap_or(0) - fraction_len as i64;
+        match mantissa.finish(shift) {
+            Ok(Some(magnitude)) => Ok(SafeDecN(SafeIntN::from_parts(negative, magnitude))),
+            Ok(None) => Err(ParseError::OutOfRange),
+            Err(excess) => Err(ParseError::TooManyFractionalDigits {
+                expected: D,
+                actual: D + excess,
+            }),
+        }
+    }
+}
+
+/// Writes the value with `D` fractional digits, omitting the `.` when `D` is 0.
+///
+/// Width, fill, alignment, `+` and `0` flags are honored. A precision (`{:.2}`) sets the number
+/// of fractional digits instead, padding with zeros or rounding half to even. Values that round
+/// to zero are printed without a sign.
+impl<const LIMBS: usize, const D: usize> Display for SafeDecN<LIMBS, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let places = f.precision().unwrap_or(D);
+        let (magnitude, scale) = self.rounded_magnitude(places);
+        let non_negative = !self.0.negative || limbs_are_zero(&magnitude);
+        let digits = digit_count(magnitude);
+        let split = digits.saturating_sub(scale);
+        let point = usize::from(places > 0);
+        let len = split.max(1) + point + places;
+        pad_number(f, non_negative, len, |f| {
+            if split == 0 {
+                f.write_char('0')?;
+                if places > 0 {
+                    f.write_char('.')?;
+                }
+                for _ in digits..scale {
+                    f.write_char('0')?;
+                }
+            }
+            let mut index = 0;
+            for_each_digit(magnitude, &mut |digit| {
+                if index == split && split > 0 && places > 0 {
+                    f.write_char('.')?;
+                }
+                index += 1;
+                f.write_char(digit)
+            })?;
+            if split == digits && split > 0 && places > 0 {
+                f.write_char('.')?;
+            }
+            for _ in scale..places {
+                f.write_char('0')?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<const LIMBS: usize, const D: usize> Neg for SafeDecN<LIMBS, D> {
+    type Output = SafeDecN<LIMBS, D>;
+
+    #[inline(always)]
+    fn neg(self) -> SafeDecN<LIMBS, D> {
+        SafeDecN(-self.0)
+    }
+}
+
+impl<const LIMBS: usize, const D: usize> Neg for &SafeDecN<LIMBS, D> {
+    type Output = SafeDecN<LIMBS, D>;
+
+    #[inline(always)]
+    fn neg(self) -> SafeDecN<LIMBS, D> {
+        SafeDecN(-self.0)
+    }
+}
+
+macro_rules! impl_fixed_decimal_ops {
+    ($($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr);* $(;)?) => {
+        $(
+            impl<const LIMBS: usize, const D: usize> $trait for SafeDecN<LIMBS, D> {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    let ($a, $b) = (self, other);
+                    $body
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<&SafeDecN<LIMBS, D>>
+                for SafeDecN<LIMBS, D>
+            {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    self.$method(*other)
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<SafeDecN<LIMBS, D>>
+                for &SafeDecN<LIMBS, D>
+            {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    (*self).$method(other)
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<&SafeDecN<LIMBS, D>>
+                for &SafeDecN<LIMBS, D>
+            {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    (*self).$method(*other)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_decimal_ops!(
+    Add, add, |a, b| Some(SafeDecN(a.0.try_add(b.0)?));
+    Sub, sub, |a, b| Some(SafeDecN(a.0.try_sub(b.0)?));
+    Mul, mul, |a, b| a.try_mul(b);
+    Div, div, |a, b| a.try_div(b);
+    Rem, rem, |a, b| Some(SafeDecN(a.0.try_rem(b.0)?));
+    BitAnd, bitand, |a, b| Some(SafeDecN(a.0.try_bitand(b.0)?));
+    BitOr, bitor, |a, b| Some(SafeDecN(a.0.try_bitor(b.0)?));
+    BitXor, bitxor, |a, b| Some(SafeDecN(a.0.try_bitxor(b.0)?));
+);
+
+/// Implements decimal operators against every primitive in both directions. The first
+/// expression computes `decimal op primitive` and the second `primitive op decimal`, each from
+/// the decimal and the primitive converted to a [`SafeIntN`].
+macro_rules! impl_fixed_decimal_prim_ops {
+    ($(
+        $trait:ident, $method:ident,
+        |$d:ident, $p:ident| $dec:expr,
+        |$q:ident, $e:ident| $prim_body:expr
+    );* $(;)?) => {
+        $(
+            impl_fixed_decimal_prim_ops!(
+                @each $trait, $method, $d, $p, $dec, $q, $e, $prim_body,
+                [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+            );
+        )*
+    };
+    (
+        @each $trait:ident, $method:ident,
+        $d:ident, $p:ident, $dec:expr,
+        $q:ident, $e:ident, $prim_body:expr,
+        [$($prim:ty),*]
+    ) => {
+        $(
+            impl<const LIMBS: usize, const D: usize> $trait<$prim> for SafeDecN<LIMBS, D> {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeDecN<LIMBS, D>> {
+                    let ($d, $p): (SafeDecN<LIMBS, D>, SafeIntN<LIMBS>) = (self, other.to_fixed()?);
+                    $dec
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<$prim> for &SafeDecN<LIMBS, D> {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeDecN<LIMBS, D>> {
+                    (*self).$method(other)
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<SafeDecN<LIMBS, D>> for $prim {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    let ($q, $e): (SafeIntN<LIMBS>, SafeDecN<LIMBS, D>) = (self.to_fixed()?, other);
+                    $prim_body
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> $trait<&SafeDecN<LIMBS, D>> for $prim {
+                type Output = Option<SafeDecN<LIMBS, D>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeDecN<LIMBS, D>) -> Option<SafeDecN<LIMBS, D>> {
+                    self.$method(*other)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_decimal_prim_ops!(
+    Add, add,
+    |d, p| d + SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? + d;
+    Sub, sub,
+    |d, p| d - SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? - d;
+    Rem, rem,
+    |d, p| d % SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? % d;
+    BitAnd, bitand,
+    |d, p| d & SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? & d;
+    BitOr, bitor,
+    |d, p| d | SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? | d;
+    BitXor, bitxor,
+    |d, p| d ^ SafeDecN::<LIMBS, D>::scaled(p)?,
+    |p, d| SafeDecN::<LIMBS, D>::scaled(p)? ^ d;
+    Mul, mul,
+    |d, p| Some(SafeDecN(d.0.try_mul(p)?)),
+    |p, d| Some(SafeDecN(p.try_mul(d.0)?));
+    Div, div,
+    |d, p| Some(SafeDecN(d.0.try_div(p)?)),
+    |p, d| Some(SafeDecN(SafeDecN::<LIMBS, D>::scaled(p)?.0.try_div(d.0)?));
+);
+
+impl_fixed_assign_ops!(
+    [const LIMBS: usize, const D: usize] SafeDecN<LIMBS, D>;
+    AddAssign, add_assign, Add, add;
+    SubAssign, sub_assign, Sub, sub;
+    MulAssign, mul_assign, Mul, mul;
+    DivAssign, div_assign, Div, div;
+    RemAssign, rem_assign, Rem, rem;
+    BitAndAssign, bitand_assign, BitAnd, bitand;
+    BitOrAssign, bitor_assign, BitOr, bitor;
+    BitXorAssign, bitxor_assign, BitXor, bitxor;
+);
+
+macro_rules! impl_fixed_decimal_cmp_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl<const LIMBS: usize, const D: usize> PartialEq<$prim> for SafeDecN<LIMBS, D> {
+                #[inline(always)]
+                fn eq(&self, other: &$prim) -> bool {
+                    other.to_fixed().and_then(SafeDecN::scaled) == Some(*self)
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> PartialOrd<$prim> for SafeDecN<LIMBS, D> {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    let scaled = other.to_fixed().and_then(SafeDecN::<LIMBS, D>::scaled);
+                    Some(self.0.cmp_operand(scaled.map(|d| d.0), other.is_below_zero()))
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> PartialEq<SafeDecN<LIMBS, D>> for $prim {
+                #[inline(always)]
+                fn eq(&self, other: &SafeDecN<LIMBS, D>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> PartialOrd<SafeDecN<LIMBS, D>> for $prim {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeDecN<LIMBS, D>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_decimal_cmp_for_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl<const LIMBS: usize, const D: usize> SafeDecN<LIMBS, D> {
+    /// Returns the value as an integer, or `None` if it has a fractional part.
+    fn whole(&self) -> Option<SafeIntN<LIMBS>> {
+        let mut magnitude = self.0.magnitude;
+        let mut remaining = D;
+        while remaining > 0 {
+            let step = remaining.min(CHUNK_DIGITS);
+            if div_small(&mut magnitude, 10u64.pow(step as u32), 0) != 0 {
+                return None;
+            }
+            remaining -= step;
+        }
+        Some(SafeIntN::from_parts(self.0.negative, magnitude))
+    }
+}
+
+macro_rules! impl_primitive_try_from_fixed_decimal {
+    ($($prim:ident),*) => {
+        $(
+            impl<const LIMBS: usize, const D: usize> TryFrom<SafeDecN<LIMBS, D>> for $prim {
+                type Error = ConversionError;
+
+                /// Converts whole values only; a non-zero fractional part is an error rather
+                /// than being rounded away.
+                #[inline(always)]
+                fn try_from(value: SafeDecN<LIMBS, D>) -> Result<$prim, ConversionError> {
+                    let whole = value.whole().ok_or(ConversionError::Fractional {
+                        target: stringify!($prim),
+                    })?;
+                    $prim::try_from(whole)
+                }
+            }
+
+            impl<const LIMBS: usize, const D: usize> TryFrom<&SafeDecN<LIMBS, D>> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeDecN<LIMBS, D>) -> Result<$prim, ConversionError> {
+                    $prim::try_from(*value)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_try_from_fixed_decimal!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> From<SafeDecN<LIMBS, D>> for SafeDec<D> {
+    #[inline(always)]
+    fn from(value: SafeDecN<LIMBS, D>) -> SafeDec<D> {
+        SafeDec::from_raw(value.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> From<&SafeDecN<LIMBS, D>> for SafeDec<D> {
+    #[inline(always)]
+    fn from(value: &SafeDecN<LIMBS, D>) -> SafeDec<D> {
+        SafeDec::from_raw(value.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> TryFrom<&SafeDec<D>> for SafeDecN<LIMBS, D> {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: &SafeDec<D>) -> Result<SafeDecN<LIMBS, D>, ConversionError> {
+        SafeIntN::try_from(value.raw()).map(SafeDecN)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> TryFrom<SafeDec<D>> for SafeDecN<LIMBS, D> {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: SafeDec<D>) -> Result<SafeDecN<LIMBS, D>, ConversionError> {
+        SafeDecN::try_from(&value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> PartialEq<SafeDec<D>> for SafeDecN<LIMBS, D> {
+    #[inline(always)]
+    fn eq(&self, other: &SafeDec<D>) -> bool {
+        self.0 == *other.raw()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> PartialOrd<SafeDec<D>> for SafeDecN<LIMBS, D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeDec<D>) -> Option<Ordering> {
+        self.0.partial_cmp(other.raw())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> PartialEq<SafeDecN<LIMBS, D>> for SafeDec<D> {
+    #[inline(always)]
+    fn eq(&self, other: &SafeDecN<LIMBS, D>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> PartialOrd<SafeDecN<LIMBS, D>> for SafeDec<D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeDecN<LIMBS, D>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Same wire format as [`SafeDec`]: the scaled integer encoded like a [`SafeIntN`].
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> Encode for SafeDecN<LIMBS, D> {
+    #[inline(always)]
+    fn encode_ext(
+        &self,
+        writer: &mut impl Write,
+        dedupe_encoder: Option<&mut DedupeEncoder>,
+    ) -> lencode::Result<usize> {
+        self.0.encode_ext(writer, dedupe_encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize, const D: usize> Decode for SafeDecN<LIMBS, D> {
+    #[inline(always)]
+    fn decode_ext(
+        reader: &mut impl Read,
+        dedupe_decoder: Option<&mut DedupeDecoder>,
+    ) -> lencode::Result<Self> {
+        Ok(SafeDecN(SafeIntN::decode_ext(reader, dedupe_decoder)?))
+    }
+}
+
+#[inline(always)]
+fn limbs_are_zero(limbs: &[u64]) -> bool {
+    limbs.iter().all(|&limb| limb == 0)
+}
+
+/// Compares two magnitudes of the same length.
+#[inline(always)]
+fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Number of leading zero bits; `64 * limbs.len()` for zero.
+#[inline(always)]
+fn leading_zeros(limbs: &[u64]) -> usize {
+    match limbs.iter().rposition(|&limb| limb != 0) {
+        Some(top) => 64 * (limbs.len() - 1 - top) + limbs[top].leading_zeros() as usize,
+        None => 64 * limbs.len(),
+    }
+}
+
+/// Adds `b` into `a` and returns the carry out.
+#[inline(always)]
+fn add_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut carry = false;
+    for (limb, &other) in a.iter_mut().zip(b) {
+        let (sum, overflow) = limb.overflowing_add(other);
+        let (sum, overflow_carry) = sum.overflowing_add(u64::from(carry));
+        *limb = sum;
+        carry = overflow || overflow_carry;
+    }
+    carry
+}
+
+/// Subtracts `b` from `a`, wrapping, and returns the borrow out.
+#[inline(always)]
+fn sub_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut borrow = false;
+    for (limb, &other) in a.iter_mut().zip(b) {
+        let (difference, overflow) = limb.overflowing_sub(other);
+        let (difference, overflow_borrow) = difference.overflowing_sub(u64::from(borrow));
+        *limb = difference;
+        borrow = overflow || overflow_borrow;
+    }
+    borrow
+}
+
+/// Replaces `limbs` with its two's complement negation.
+#[inline(always)]
+fn negate_limbs(limbs: &mut [u64]) {
+    let mut carry = true;
+    for limb in limbs {
+        (*limb, carry) = (!*limb).overflowing_add(u64::from(carry));
+    }
+}
+
+/// Subtracts one from a non-zero magnitude.
+#[inline(always)]
+fn decrement_limbs(limbs: &mut [u64]) {
+    for limb in limbs {
+        let (difference, borrow) = limb.overflowing_sub(1);
+        *limb = difference;
+        if !borrow {
+            return;
+        }
+    }
+}
+
+/// Computes `limbs * factor + carry` in place and returns the limb carried out.
+#[inline(always)]
+fn mul_small(limbs: &mut [u64], factor: u64, carry: u64) -> u64 {
+    let mut carry = carry;
+    for limb in limbs {
+        let product = u128::from(*limb) * u128::from(factor) + u128::from(carry);
+        *limb = product as u64;
+        carry = (product >> 64) as u64;
+    }
+    carry
+}
+
+/// Divides `remainder * 2^(64 * len) + limbs` by `divisor` in place, where
+/// `remainder < divisor`, and returns the new remainder.
+#[inline(always)]
+fn div_small(limbs: &mut [u64], divisor: u64, remainder: u64) -> u64 {
+    let mut remainder = remainder;
+    for limb in limbs.iter_mut().rev() {
+        let dividend = (u128::from(remainder) << 64) | u128::from(*limb);
+        *limb = (dividend / u128::from(divisor)) as u64;
+        remainder = (dividend % u128::from(divisor)) as u64;
+    }
+    remainder
+}
+
+/// Writes the double-width product `a * b` to `low` and `high`.
+fn mul_wide(a: &[u64], b: &[u64], low: &mut [u64], high: &mut [u64]) {
+    let len = a.len();
+    low.fill(0);
+    high.fill(0);
+    for (i, &left) in a.iter().enumerate() {
+        if left == 0 {
+            continue;
+        }
+        let mut carry = 0u64;
+        for (j, &right) in b.iter().enumerate() {
+            let slot = if i + j < len {
+                &mut low[i + j]
+            } else {
+                &mut high[i + j - len]
+            };
+            let product =
+                u128::from(left) * u128::from(right) + u128::from(*slot) + u128::from(carry);
+            *slot = product as u64;
+            carry = (product >> 64) as u64;
+        }
+        // No earlier row reaches position `i + len`.
+        high[i] = carry;
+    }
+}
+
+/// Divides `high * 2^(64 * len) + low` by `divisor`, where `high < divisor`, writing the
+/// quotient to `quotient` and leaving the remainder in `high`.
+fn div_wide(high: &mut [u64], low: &[u64], divisor: &[u64], quotient: &mut [u64]) {
+    quotient.copy_from_slice(low);
+    if limbs_are_zero(&divisor[1..]) {
+        let remainder = div_small(quotient, divisor[0], high[0]);
+        high.fill(0);
+        high[0] = remainder;
+        return;
+    }
+    // Shift-and-subtract long division. The remainder is below `divisor` before each shift, so
+    // after it the value is below `2 * divisor` and one wrapping subtraction brings it back.
+    for bit in (0..64 * low.len()).rev() {
+        let incoming = (low[bit / 64] >> (bit % 64)) & 1;
+        let mut carry = incoming;
+        for limb in high.iter_mut() {
+            let next = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+        let limb = &mut quotient[bit / 64];
+        *limb &= !(1 << (bit % 64));
+        if carry == 1 || cmp_limbs(high, divisor) != Ordering::Less {
+            sub_limbs(high, divisor);
+            *limb |= 1 << (bit % 64);
+        }
+    }
+}
+
+/// Cursor over a literal being parsed in place. Errors carry the same indices as the `quoth`
+/// parsers' (every character before an error position is ASCII, so byte and character offsets
+/// agree).
+#[derive(Copy, Clone)]
+struct Literal<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Literal<'a> {
+    /// Starts at the beginning of `text`, failing with [`ParseError::Empty`] if it is empty.
+    fn new(text: &'a str) -> Result<Literal<'a>, ParseError> {
+        if text.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        Ok(Literal { text, position: 0 })
+    }
+
+    #[inline(always)]
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    /// Consumes `c` if it comes next.
+    #[inline(always)]
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        self.position += usize::from(found);
+        found
+    }
+
+    /// Consumes a case-insensitive `0x`, `0o` or `0b` prefix and returns the radix it selects,
+    /// or 10 without one.
+    fn radix_prefix(&mut self) -> u32 {
+        let radix = match self.text.as_bytes()[self.position..] {
+            [b'0', b'x' | b'X', ..] => 16,
+            [b'0', b'o' | b'O', ..] => 8,
+            [b'0', b'b' | b'B', ..] => 2,
+            _ => return 10,
+        };
+        self.position += 2;
+        radix
+    }
+
+    /// Passes one or more digits in `radix` to `push` and returns how many there were. With
+    /// none, a character in their place is an invalid digit and the end of input is a missing
+    /// one.
+    fn digits(&mut self, radix: u32, mut push: impl FnMut(u32)) -> Result<usize, ParseError> {
+        let start = self.position;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(radix)) {
+            push(digit);
+            self.position += 1;
+        }
+        if self.position > start {
+            return Ok(self.position - start);
+        }
+        Err(match self.peek() {
+            Some(found) => ParseError::InvalidDigit {
+                index: self.position,
+                found,
+            },
+            None => ParseError::MissingDigits {
+                index: self.position,
+            },
+        })
+    }
+
+    /// Parses an optional `e`/`E` exponent suffix with an optional sign.
+    fn exponent(&mut self) -> Result<Option<i64>, ParseError> {
+        if !self.eat('e') && !self.eat('E') {
+            return Ok(None);
+        }
+        let negative = self.eat('-');
+        if !negative {
+            self.eat('+');
+        }
+        let index = self.position;
+        let limit = i64::from(MAX_EXPONENT);
+        let mut magnitude = 0i64;
+        self.digits(10, |digit| {
+            magnitude = (magnitude * 10 + i64::from(digit)).min(limit + 1);
+        })?;
+        if magnitude > limit {
+            return Err(ParseError::ExponentOutOfRange { index });
+        }
+        Ok(Some(if negative { -magnitude } else { magnitude }))
+    }
+
+    /// Requires the number to end here: letters and digits continuing the number are invalid
+    /// digits, while anything else is trailing text.
+    fn end(&self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(found) if found.is_ascii_alphanumeric() => Err(ParseError::InvalidDigit {
+                index: self.position,
+                found,
+            }),
+            Some(_) => Err(ParseError::TrailingCharacters {
+                index: self.position,
+            }),
+        }
+    }
+}
+
+/// Digits of a literal, accumulated into `LIMBS` words as they are read.
+///
+/// Zeros after the last non-zero digit are only counted, so a literal such as `1000e-3` fits
+/// even when its digits as a whole would not.
+#[derive(Copy, Clone)]
+struct Mantissa<const LIMBS: usize> {
+    magnitude: [u64; LIMBS],
+    radix: u32,
+    /// Digits not yet folded into `magnitude`, as a value below `radix^pending`.
+    chunk: u64,
+    pending: u32,
+    /// Zeros read since the last non-zero digit.
+    zeros: usize,
+    nonzero: bool,
+    overflow: bool,
+}
+
+impl<const LIMBS: usize> Mantissa<LIMBS> {
+    fn new(radix: u32) -> Mantissa<LIMBS> {
+        Mantissa {
+            magnitude: [0; LIMBS],
+            radix,
+            chunk: 0,
+            pending: 0,
+            zeros: 0,
+            nonzero: false,
+            overflow: false,
+        }
+    }
+
+    fn push(&mut self, digit: u32) {
+        if digit == 0 {
+            self.zeros += usize::from(self.nonzero);
+            return;
+        }
+        for _ in 0..core::mem::take(&mut self.zeros) {
+            self.append(0);
+        }
+        self.append(digit);
+        self.nonzero = true;
+    }
+
+    /// Appends a digit, folding the chunk into `magnitude` once another digit could overflow
+    /// it.
+    #[inline(always)]
+    fn append(&mut self, digit: u32) {
+        let radix = u64::from(self.radix);
+        self.chunk = self.chunk * radix + u64::from(digit);
+        self.pending += 1;
+        if self.pending == u64::MAX.ilog(radix) {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.pending > 0 && !self.overflow {
+            let factor = u64::from(self.radix).pow(self.pending);
+            self.overflow = mul_small(&mut self.magnitude, factor, self.chunk) != 0;
+        }
+        (self.chunk, self.pending) = (0, 0);
+    }
+
+    /// Returns the magnitude times `radix^shift`, or `None` if it does not fit. A negative
+    /// shift that would drop non-zero digits fails with the number of digits too many.
+    fn finish(mut self, shift: i64) -> Result<Option<[u64; LIMBS]>, usize> {
+        if !self.nonzero {
+            return Ok(Some([0; LIMBS]));
+        }
+        let shift = self.zeros as i64 + shift;
+        if shift < 0 {
+            return Err(shift.unsigned_abs() as usize);
+        }
+        // A non-zero magnitude overflows within `64 * LIMBS` digits, so this stops early.
+        for _ in 0..shift {
+            if self.overflow {
+                break;
+            }
+            self.append(0);
+        }
+        self.flush();
+        Ok((!self.overflow).then_some(self.magnitude))
+    }
+}
+
+/// Number of decimal digits in `magnitude`, counting zero as one digit.
+fn digit_count<const LIMBS: usize>(mut magnitude: [u64; LIMBS]) -> usize {
+    let mut count = 0;
+    loop {
+        let chunk = div_small(&mut magnitude, CHUNK, 0);
+        if limbs_are_zero(&magnitude) {
+            return count + chunk.checked_ilog10().unwrap_or(0) as usize + 1;
+        }
+        count += CHUNK_DIGITS;
+    }
+}
+
+/// Passes the decimal digits of `magnitude` to `emit`, most significant first, without a
+/// buffer sized by the value: each level peels off the lowest 19 digits.
+fn for_each_digit<const LIMBS: usize>(
+    mut magnitude: [u64; LIMBS],
+    emit: &mut impl FnMut(char) -> fmt::Result,
+) -> fmt::Result {
+    let chunk = div_small(&mut magnitude, CHUNK, 0);
+    let mut digits = [b'0'; CHUNK_DIGITS];
+    let mut value = chunk;
+    for digit in digits.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    let start = if limbs_are_zero(&magnitude) {
+        CHUNK_DIGITS - (chunk.checked_ilog10().unwrap_or(0) as usize + 1)
+    } else {
+        for_each_digit(magnitude, emit)?;
+        0
+    };
+    digits[start..]
+        .iter()
+        .try_for_each(|&digit| emit(char::from(digit)))
+}
+
+/// Writes a number of `len` characters produced by `body` with its sign, honoring width,
+/// fill, alignment, `+` and `0` flags the way [`fmt::Formatter::pad_integral`] does.
+fn pad_number(
+    f: &mut fmt::Formatter<'_>,
+    non_negative: bool,
+    len: usize,
+    body: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let sign = if !non_negative {
+        Some('-')
+    } else if f.sign_plus() {
+        Some('+')
+    } else {
+        None
+    };
+    let len = len + usize::from(sign.is_some());
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    if padding == 0 || f.sign_aware_zero_pad() {
+        if let Some(sign) = sign {
+            f.write_char(sign)?;
+        }
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return body(f);
+    }
+    let fill = f.fill();
+    let before = match f.align() {
+        Some(fmt::Alignment::Left) => 0,
+        Some(fmt::Alignment::Center) => padding / 2,
+        Some(fmt::Alignment::Right) | None => padding,
+    };
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    if let Some(sign) = sign {
+        f.write_char(sign)?;
+    }
+    body(f)?;
+    for _ in before..padding {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+fn fixed_test_values() -> Vec<SafeInt> {
+    let mut values = Vec::new();
+    for magnitude in [
+        SafeInt::zero(),
+        SafeInt::one(),
+        SafeInt::from(7),
+        SafeInt::from(u64::MAX),
+        SafeInt::from(u64::MAX) + SafeInt::one(),
+        SafeInt::from(0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321u128),
+        SafeInt::from(u128::MAX),
+        SafeInt::from(u128::MAX) + SafeInt::one(),
+        SafeInt::from(3).pow(150),
+        SafeInt::from(2).pow(192) - SafeInt::one(),
+    ] {
+        values.push(-&magnitude);
+        values.push(magnitude);
+    }
+    values
+}
+
+#[cfg(all(test, feature = "alloc"))]
+fn check_int_ops<const LIMBS: usize>() {
+    let fit = |value: SafeInt| SafeIntN::<LIMBS>::try_from(&value).ok();
+    for a in fixed_test_values() {
+        let Some(x) = fit(a.clone()) else {
+            continue;
+        };
+        assert_eq!(SafeInt::from(x), a);
+        assert_eq!(-x, fit(-&a).unwrap());
+        for b in fixed_test_values() {
+            let Some(y) = fit(b.clone()) else {
+                continue;
+            };
+            assert_eq!(x.cmp(&y), a.cmp(&b), "{a} cmp {b}");
+            assert_eq!(x + y, fit(&a + &b), "{a} + {b}");
+            assert_eq!(x - y, fit(&a - &b), "{a} - {b}");
+            assert_eq!(x * y, fit(&a * &b), "{a} * {b}");
+            assert_eq!(x / y, (&a / &b).and_then(fit), "{a} / {b}");
+            assert_eq!(x % y, (&a % &b).and_then(fit), "{a} % {b}");
+            assert_eq!(x & y, fit(&a & &b), "{a} & {b}");
+            assert_eq!(x | y, fit(&a | &b), "{a} | {b}");
+            assert_eq!(x ^ y, fit(&a ^ &b), "{a} ^ {b}");
+            let expected = a.clone().div_rem(b.clone());
+            let expected = expected.and_then(|(q, r)| Some((fit(q)?, fit(r)?)));
+            assert_eq!(x.div_rem(y), expected, "{a} div_rem {b}");
+            for mode in RoundingMode::ALL {
+                let expected = a.div_round(&b, mode).and_then(fit);
+                assert_eq!(x.div_round(&y, mode), expected, "{a} / {b} {mode:?}");
+            }
+            let mut assigned = x;
+            assigned *= y;
+            assert_eq!(assigned, (x * y).unwrap_or(x), "{a} *= {b}");
+            assigned = x;
+            assigned /= &y;
+            assert_eq!(assigned, (x / y).unwrap_or(x), "{a} /= {b}");
+        }
+        for exp in [0, 1, 2, 3, 7, 64, 129] {
+            assert_eq!(x.pow(exp), fit(a.clone().pow(exp)), "{a} pow {exp}");
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_int_matches_safe_int() {
+    check_int_ops::<1>();
+    check_int_ops::<2>();
+    check_int_ops::<3>();
+    check_int_ops::<4>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_int_bounds_and_primitives() {
+    type Int = SafeIntN<1>;
+    assert_eq!(Int::MAX + 1u8, None);
+    assert_eq!(Int::MIN - 1u8, None);
+    assert_eq!(Int::MAX * Int::MAX, None);
+    assert_eq!(-Int::MIN, Int::MAX);
+    assert_eq!(Int::from(5) / 0u8, None);
+    assert_eq!(Int::from(-7) % 2i8, Some(Int::from(-1)));
+    assert_eq!(100u8 - Int::from(101), Some(Int::from(-1)));
+    assert_eq!(Int::zero() * u128::MAX, None);
+    assert_eq!(Int::try_from(u128::from(u64::MAX)), Ok(Int::MAX));
+    assert_eq!(
+        Int::try_from(u128::MAX),
+        Err(ConversionError::Overflow { target: "SafeIntN" })
+    );
+    assert_eq!(
+        Int::try_from(i128::MIN),
+        Err(ConversionError::Underflow { target: "SafeIntN" })
+    );
+    assert_eq!(i64::try_from(Int::from(i64::MIN)), Ok(i64::MIN));
+    assert_eq!(
+        i64::try_from(Int::MIN),
+        Err(ConversionError::Underflow { target: "i64" })
+    );
+    assert_eq!(
+        u8::try_from(&Int::from(256)),
+        Err(ConversionError::Overflow { target: "u8" })
+    );
+    assert_eq!(i128::try_from(SafeIntN::<2>::from(-3)), Ok(-3));
+    assert_eq!(
+        SafeIntN::<2>::try_from(i128::MIN).map(SafeInt::from),
+        Ok(SafeInt::from(i128::MIN))
+    );
+
+    assert!(Int::MAX < u128::MAX);
+    assert!(Int::MIN > i128::MIN);
+    assert!(Int::from(3) == 3u128);
+    assert!(-2i8 < Int::from(-1));
+    let (wide, narrow, nine) = (
+        SafeInt::from(u128::MAX),
+        SafeInt::from(i128::MIN),
+        SafeInt::from(9),
+    );
+    assert!(Int::MAX < wide);
+    assert!(narrow < Int::MIN);
+    assert!(nine == Int::from(9));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_int_display_and_parse() {
+    type Int = SafeIntN<3>;
+    let big = SafeInt::from(3).pow(120);
+    for value in [SafeInt::zero(), SafeInt::from(-42), big.clone(), -big] {
+        let fixed = Int::try_from(&value).unwrap();
+        for (expected, actual) in [
+            (format!("{value}"), format!("{fixed}")),
+            (format!("{value:+}"), format!("{fixed:+}")),
+            (format!("{value:08}"), format!("{fixed:08}")),
+            (format!("{value:>8}"), format!("{fixed:>8}")),
+            (format!("{value:*^70}"), format!("{fixed:*^70}")),
+            (format!("{value:<9}|"), format!("{fixed:<9}|")),
+        ] {
+            assert_eq!(actual, expected);
+        }
+        assert_eq!(fixed.to_string().parse::<Int>(), Ok(fixed));
+    }
+    assert_eq!(format!("{:?}", Int::from(-5)), "SafeIntN(-5)");
+    assert_eq!("0x1f".parse::<Int>(), Ok(Int::from(31)));
+    assert_eq!("-0".parse::<Int>(), Ok(Int::zero()));
+    assert_eq!(
+        "+5".parse::<Int>(),
+        "+5".parse::<SafeInt>().map(|_| Int::zero())
+    );
+    assert_eq!(
+        "1a".parse::<Int>().unwrap_err(),
+        "1a".parse::<SafeInt>().unwrap_err()
+    );
+    assert_eq!(
+        "18446744073709551616".parse::<SafeIntN<1>>(),
+        Err(ParseError::OutOfRange)
+    );
+    assert_eq!(
+        "0x10000000000000000".parse::<SafeIntN<1>>(),
+        Err(ParseError::OutOfRange)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_dec_matches_safe_dec() {
+    fn check<const LIMBS: usize, const D: usize>() {
+        let fit = |value: SafeDec<D>| SafeDecN::<LIMBS, D>::try_from(&value).ok();
+        for a in fixed_test_values() {
+            let a = SafeDec::<D>::from_raw(a);
+            let Some(x) = fit(a.clone()) else {
+                continue;
+            };
+            assert_eq!(x.to_string(), a.to_string());
+            assert_eq!(format!("{x:.1}"), format!("{a:.1}"));
+            assert_eq!(format!("{x:+.0}"), format!("{a:+.0}"));
+            assert_eq!(format!("{x:^40.4}"), format!("{a:^40.4}"));
+            assert_eq!(x.to_string().parse(), Ok(x));
+            assert_eq!(x * 3i8, fit(a.clone() * 3i8));
+            assert_eq!(x + 5u64, fit(a.clone() + 5u64));
+            assert_eq!(x % 2u8, (a.clone() % 2u8).and_then(fit));
+            assert_eq!(7u16 / x, (7u16 / a.clone()).and_then(fit));
+            assert_eq!(x.partial_cmp(&-3i32), a.partial_cmp(&-3i32));
+            assert_eq!(i64::try_from(x).ok(), i64::try_from(&a).ok());
+            for b in fixed_test_values() {
+                let b = SafeDec::<D>::from_raw(b);
+                let Some(y) = fit(b.clone()) else {
+                    continue;
+                };
+                assert_eq!(x + y, fit(a.clone() + b.clone()));
+                assert_eq!(x * y, fit(a.clone() * b.clone()), "{a} * {b}");
+                assert_eq!(x / y, (a.clone() / b.clone()).and_then(fit), "{a} / {b}");
+                assert_eq!(x % y, (a.clone() % b.clone()).and_then(fit));
+                assert_eq!(x.partial_cmp(&b), a.partial_cmp(&b));
+            }
+        }
+    }
+    check::<1, 0>();
+    check::<1, 2>();
+    check::<2, 6>();
+    check::<3, 18>();
+    check::<4, 40>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_dec_parse_and_convert() {
+    type Dec = SafeDecN<1, 2>;
+    assert_eq!("1.5".parse::<Dec>().unwrap().to_string(), "1.50");
+    assert_eq!("-0.05".parse::<Dec>().unwrap().to_string(), "-0.05");
+    assert_eq!("1.5e1".parse::<Dec>().unwrap().to_string(), "15.00");
+    assert_eq!(
+        "1.234".parse::<Dec>().unwrap_err(),
+        "1.234".parse::<SafeDec<2>>().unwrap_err()
+    );
+    assert_eq!(
+        "5".parse::<Dec>().unwrap_err(),
+        "5".parse::<SafeDec<2>>().unwrap_err()
+    );
+    assert_eq!(
+        "184467440737095516.16".parse::<Dec>(),
+        Err(ParseError::OutOfRange)
+    );
+    assert_eq!(format!("{:.1}", "-0.04".parse::<Dec>().unwrap()), "0.0");
+    assert_eq!(
+        format!("{:08.3}", "-2.5".parse::<Dec>().unwrap()),
+        "-002.500"
+    );
+    assert_eq!(
+        u8::try_from("2.50".parse::<Dec>().unwrap()),
+        Err(ConversionError::Fractional { target: "u8" })
+    );
+    assert_eq!(u8::try_from("2.00".parse::<Dec>().unwrap()), Ok(2));
+    let dec = Dec::from_raw(SafeIntN::from(250));
+    assert_eq!(SafeDec::from(dec).to_string(), "2.50");
+    assert_eq!(dec, "2.5".parse::<SafeDec<2>>().unwrap());
+    assert_eq!(dec.raw(), 250u16);
+    assert!(dec > 2u8 && dec < 3u8);
+}
+
+#[test]
+fn test_fixed_assign_and_rounding() {
+    type Int = SafeIntN<1>;
+    let mut value = Int::from(7);
+    value += 5u8;
+    value -= Int::from(2);
+    value *= -3i32;
+    assert_eq!(value, -30);
+    value /= 0u8;
+    assert_eq!(value, -30);
+    value %= &Int::from(7);
+    assert_eq!(value, -2);
+    value |= 1u8;
+    value &= Int::from(-4);
+    value ^= 6i64;
+    assert_eq!(value, -6);
+    let mut max = Int::MAX;
+    max += 1u8;
+    max *= 2u8;
+    assert_eq!(max, Int::MAX);
+
+    let mut price = "12.50".parse::<SafeDecN<1, 2>>().unwrap();
+    price *= 3u8;
+    price -= "0.50".parse::<SafeDecN<1, 2>>().unwrap();
+    price /= 0u8;
+    assert_eq!(price.to_string(), "37.00");
+    price /= 8u8;
+    assert_eq!(price.to_string(), "4.62");
+
+    assert_eq!(Int::from(3).pow(40).unwrap(), 12_157_665_459_056_928_801u64);
+    assert_eq!(Int::from(3).pow(41), None);
+    assert_eq!(Int::from(-1).pow(u32::MAX).unwrap(), -1);
+    assert_eq!(Int::zero().pow(0).unwrap(), 1);
+    assert_eq!(
+        Int::from(-7).div_rem(Int::from(2)),
+        Some((Int::from(-3), Int::from(-1)))
+    );
+    assert_eq!(Int::from(7).div_rem(Int::zero()), None);
+
+    let expected = [
+        (RoundingMode::TowardZero, [2, 2, -2, -2]),
+        (RoundingMode::AwayFromZero, [3, 3, -3, -3]),
+        (RoundingMode::Floor, [2, 2, -3, -3]),
+        (RoundingMode::Ceil, [3, 3, -2, -2]),
+        (RoundingMode::HalfUp, [3, 3, -3, -3]),
+        (RoundingMode::HalfDown, [2, 3, -2, -3]),
+        (RoundingMode::HalfEven, [2, 3, -2, -3]),
+    ];
+    for (mode, quotients) in expected {
+        for (numerator, quotient) in [5i8, 11, -5, -11].into_iter().zip(quotients) {
+            let divisor = if numerator.unsigned_abs() == 5 { 2 } else { 4 };
+            let rounded = Int::from(numerator).div_round(&Int::from(divisor), mode);
+            assert_eq!(
+                rounded.unwrap(),
+                quotient,
+                "{numerator} / {divisor} {mode:?}"
+            );
+        }
+    }
+    // Doubling a remainder above half of `2^64` carries out of the limb.
+    let rounded = Int::MAX.div_round(&(Int::MAX - 1u8).unwrap(), RoundingMode::HalfDown);
+    assert_eq!(rounded.unwrap(), 1);
+    assert_eq!(Int::MAX.div_round(&Int::zero(), RoundingMode::Floor), None);
+    assert_eq!(
+        Int::MIN
+            .div_round(&Int::one(), RoundingMode::Floor)
+            .unwrap(),
+        Int::MIN
+    );
+}
+
+#[test]
+fn test_fixed_parse_in_place() {
+    type Int = SafeIntN<1>;
+    type Dec = SafeDecN<1, 2>;
+    assert_eq!("-0X1F".parse::<Int>(), Ok(Int::from(-31)));
+    assert_eq!("0o17".parse::<Int>(), Ok(Int::from(15)));
+    assert_eq!("0b101".parse::<Int>(), Ok(Int::from(5)));
+    assert_eq!("1.5e1".parse::<Int>(), Ok(Int::from(15)));
+    assert_eq!("12000e-3".parse::<Int>(), Ok(Int::from(12)));
+    // The digits alone do not fit in one limb, but their trailing zero is cancelled.
+    assert_eq!("184467440737095516150e-1".parse::<Int>(), Ok(Int::MAX));
+    assert_eq!("0xffffffffffffffff".parse::<Int>(), Ok(Int::MAX));
+    assert_eq!(
+        "0x10000000000000000".parse::<Int>(),
+        Err(ParseError::OutOfRange)
+    );
+    assert_eq!("1e20".parse::<Int>(), Err(ParseError::OutOfRange));
+    assert_eq!(
+        "15e-1".parse::<Int>(),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 0,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        "1e10001".parse::<Int>(),
+        Err(ParseError::ExponentOutOfRange { index: 2 })
+    );
+    assert_eq!("".parse::<Int>(), Err(ParseError::Empty));
+    assert_eq!(
+        "0x".parse::<Int>(),
+        Err(ParseError::MissingDigits { index: 2 })
+    );
+    assert_eq!(
+        "1.5".parse::<Int>(),
+        Err(ParseError::TrailingCharacters { index: 1 })
+    );
+    assert_eq!(
+        "12z".parse::<Int>(),
+        Err(ParseError::InvalidDigit {
+            index: 2,
+            found: 'z'
+        })
+    );
+
+    assert_eq!("-1.5e-1".parse::<Dec>().unwrap().to_string(), "-0.15");
+    assert_eq!("0.125e1".parse::<Dec>().unwrap().to_string(), "1.25");
+    assert_eq!("1e-2".parse::<Dec>().unwrap().to_string(), "0.01");
+    assert_eq!("7".parse::<SafeDecN<1, 0>>().unwrap().to_string(), "7");
+    assert_eq!(
+        "1.00e-3".parse::<Dec>(),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        "5".parse::<Dec>(),
+        Err(ParseError::MissingFractionalDigits { index: 1 })
+    );
+    assert_eq!(
+        "5.".parse::<Dec>(),
+        Err(ParseError::MissingFractionalDigits { index: 2 })
+    );
+    assert_eq!(
+        "184467440737095516.16".parse::<Dec>(),
+        Err(ParseError::OutOfRange)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_fixed_parse_matches_shared_parsers() {
+    let inputs = [
+        "",
+        "-",
+        "--1",
+        "+5",
+        "0x",
+        "-0x1f",
+        "0XfF",
+        "0o78",
+        "0b102",
+        "0x1e5",
+        "1_000",
+        "1.5",
+        "1.5e1",
+        "1.50e1",
+        "1.e1",
+        ".5",
+        "1e",
+        "1e+",
+        "1e-2",
+        "100e-2",
+        "0e-5",
+        "-0",
+        "12ab",
+        "1 ",
+        " 1",
+        "1\u{e9}",
+        "\u{ff11}",
+        "1.5.5",
+        "1.x",
+        "1.-",
+        "1.",
+        "1",
+        "5e-3",
+        "1.2e-1",
+        "1.234",
+        "-0.05",
+        "1.0000e-2",
+        "0.0000e3",
+        "1e10001",
+        "1e99999999999",
+        "340282366920938463463374607431768211456",
+        "3402823669209384634633746074317682114560e-1",
+        "-340282366920938463463374607431768211455.99",
+    ];
+    for input in inputs {
+        let expected = input
+            .parse::<SafeInt>()
+            .and_then(|value| SafeIntN::try_from(&value).map_err(|_| ParseError::OutOfRange));
+        assert_eq!(input.parse::<SafeIntN<2>>(), expected, "{input:?}");
+        let expected = input
+            .parse::<SafeDec<2>>()
+            .and_then(|value| SafeDecN::try_from(&value).map_err(|_| ParseError::OutOfRange));
+        assert_eq!(input.parse::<SafeDecN<2, 2>>(), expected, "{input:?}");
+        let expected = input
+            .parse::<SafeDec<0>>()
+            .and_then(|value| SafeDecN::try_from(&value).map_err(|_| ParseError::OutOfRange));
+        assert_eq!(input.parse::<SafeDecN<1, 0>>(), expected, "{input:?}");
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lencode_fixed_matches_safe_int_encoding() {
+    fn check<const LIMBS: usize>() {
+        for value in fixed_test_values() {
+            let Ok(fixed) = SafeIntN::<LIMBS>::try_from(&value) else {
+                let mut buf = Vec::new();
+                value.encode(&mut buf).unwrap();
+                assert!(matches!(
+                    SafeIntN::<LIMBS>::decode(&mut Cursor::new(&buf)),
+                    Err(Error::InvalidData)
+                ));
+                continue;
+            };
+            let mut expected = Vec::new();
+            value.encode(&mut expected).unwrap();
+            let mut buf = Vec::new();
+            assert_eq!(fixed.encode(&mut buf).unwrap(), buf.len());
+            assert_eq!(buf, expected, "{value}");
+            assert_eq!(
+                SafeIntN::<LIMBS>::decode(&mut Cursor::new(&buf)).unwrap(),
+                fixed
+            );
+            let dec = SafeDecN::<LIMBS, 4>::from_raw(fixed);
+            let mut dec_buf = Vec::new();
+            dec.encode(&mut dec_buf).unwrap();
+            assert_eq!(dec_buf, expected);
+            assert_eq!(SafeDecN::decode(&mut Cursor::new(&dec_buf)).unwrap(), dec);
+        }
+    }
+    check::<1>();
+    check::<2>();
+    check::<3>();
+
+    // Eight limbs reach past the 63-byte varint limit and use the bytes path, compressed for
+    // the repetitive extremes and raw otherwise.
+    let odd = SafeIntN::<8>::try_from(SafeInt::from(3).pow(320)).unwrap();
+    for huge in [SafeIntN::<8>::MIN, SafeIntN::<8>::MAX, odd, -odd] {
+        let mut expected = Vec::new();
+        SafeInt::from(huge).encode(&mut expected).unwrap();
+        assert_eq!(expected[0], LENCODE_SAFE_INT_VARIANT_BYTES);
+        let mut buf = Vec::new();
+        assert_eq!(huge.encode(&mut buf).unwrap(), buf.len());
+        assert_eq!(buf, expected);
+        assert_eq!(SafeIntN::<8>::decode(&mut Cursor::new(&buf)).unwrap(), huge);
+        assert_eq!(
+            SafeInt::decode_strict(&mut Cursor::new(&buf)).unwrap(),
+            SafeInt::from(huge)
+        );
+    }
+
+    // Raw payloads may carry zero high bytes past the largest value, but nothing else.
+    let mut padded = Vec::from([LENCODE_SAFE_INT_VARIANT_BYTES]);
+    let mut payload = Vec::from([6u8]);
+    payload.resize(200, 0);
+    Vec::<u8>::encode_len(payload.len() << 1, &mut padded).unwrap();
+    padded.extend_from_slice(&payload);
+    assert_eq!(SafeIntN::<1>::decode(&mut Cursor::new(&padded)).unwrap(), 3);
+    *padded.last_mut().unwrap() = 1;
+    assert!(matches!(
+        SafeIntN::<1>::decode(&mut Cursor::new(&padded)),
+        Err(Error::InvalidData)
+    ));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn lencode_fixed_dedupe_roundtrip() {
+    let value = SafeIntN::<2>::MAX;
+    let mut encoder = DedupeEncoder::new();
+    let mut buf = Vec::new();
+    value.encode_ext(&mut buf, Some(&mut encoder)).unwrap();
+    value.encode_ext(&mut buf, Some(&mut encoder)).unwrap();
+    let mut decoder = DedupeDecoder::new();
+    let mut cursor = Cursor::new(&buf);
+    for _ in 0..2 {
+        let decoded = SafeIntN::<2>::decode_ext(&mut cursor, Some(&mut decoder)).unwrap();
+        assert_eq!(decoded, value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_into_matches_safe_int_encoding() {
+    fn check<const LIMBS: usize>() {
+        for value in fixed_test_values() {
+            let Ok(fixed) = SafeIntN::<LIMBS>::try_from(&value) else {
+                continue;
+            };
+            let mut expected = Vec::new();
+            value.encode(&mut expected).unwrap();
+            let mut buf = [0u8; 1 + LENCODE_MAX_VARINT_BYTES];
+            let len = fixed.encode_into(&mut buf).unwrap();
+            assert_eq!(&buf[..len], &expected[..], "{value}");
+            assert!(fixed.encode_into(&mut buf[..len - 1]).is_none());
+            assert_eq!(
+                SafeIntN::<LIMBS>::decode_from(&expected),
+                Some((fixed, len))
+            );
+            let dec = SafeDecN::<LIMBS, 4>::from_raw(fixed);
+            assert_eq!(dec.encode_into(&mut buf), Some(len));
+            assert_eq!(SafeDecN::decode_from(&expected), Some((dec, len)));
+        }
+    }
+    check::<1>();
+    check::<2>();
+    check::<3>();
+    check::<7>();
+
+    // Past 63 bytes lencode takes the bytes path, which may compress. Raw payloads still decode,
+    // compressed ones do not.
+    let odd = SafeIntN::<8>::try_from(SafeInt::from(3).pow(320)).unwrap();
+    for huge in [SafeIntN::<8>::MAX, odd] {
+        let mut expected = Vec::new();
+        SafeInt::from(huge).encode(&mut expected).unwrap();
+        assert_eq!(huge.encode_into(&mut [0u8; 128]), None);
+        let decoded = SafeIntN::<8>::decode_from(&expected);
+        let (header, _) = read_lencode_varint(&expected[1..]).unwrap();
+        if header & 1 == 0 {
+            assert_eq!(decoded, Some((huge, expected.len())));
+        } else {
+            assert_eq!(decoded, None);
+        }
+    }
+}
+
+#[test]
+fn encode_into_roundtrip_without_alloc() {
+    let mut buf = [0u8; 32];
+    for value in [
+        SafeIntN::<2>::from(0),
+        SafeIntN::<2>::from(31),
+        SafeIntN::<2>::from(-32),
+        SafeIntN::<2>::from(32),
+        SafeIntN::<2>::from(-1000),
+        SafeIntN::<2>::MAX,
+        SafeIntN::<2>::MIN,
+    ] {
+        let len = value.encode_into(&mut buf).unwrap();
+        assert_eq!(SafeIntN::<2>::decode_from(&buf[..len]), Some((value, len)));
+    }
+    assert_eq!(SafeIntN::<2>::from(31).encode_into(&mut buf), Some(1));
+    assert_eq!(buf[0], 62);
+    assert_eq!(SafeIntN::<2>::MAX.encode_into(&mut []), None);
+    assert_eq!(SafeIntN::<2>::decode_from(&[]), None);
+    assert_eq!(SafeIntN::<2>::decode_from(&[0x42, 0xcf]), None);
+}
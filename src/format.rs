@@ -0,0 +1,433 @@
+use core::fmt::Display;
+
+#[cfg(test)]
+use alloc::string::ToString;
+use alloc::{string::String, vec::Vec};
+
+use crate::{ParseError, ParseOptions, SafeDec, SafeInt, decimal::decimal_parts, parsing};
+
+/// How digits of the integer part are grouped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Grouping {
+    /// No grouping: `1234567`.
+    #[default]
+    None,
+    /// Groups of three: `1,234,567`.
+    Thousands,
+    /// Indian lakh/crore grouping, three digits then groups of two: `12,34,567`.
+    Indian,
+}
+
+impl Grouping {
+    /// Checks the sizes of separated digit groups, returning the position of the first
+    /// separator that is out of place.
+    fn misplaced_separator(self, groups: &[usize]) -> Option<usize> {
+        if groups.len() < 2 {
+            return None;
+        }
+        let last = groups.len() - 1;
+        let (first_max, middle, final_size) = match self {
+            Grouping::None => return Some(0),
+            Grouping::Thousands => (3, 3, 3),
+            Grouping::Indian => (2, 2, 3),
+        };
+        if !(1..=first_max).contains(&groups[0]) {
+            return Some(0);
+        }
+        // A wrong-sized group is blamed on the separator after it, or before it if it is last.
+        (1..=last)
+            .find(|&i| groups[i] != if i == last { final_size } else { middle })
+            .map(|i| if i == last { i - 1 } else { i })
+    }
+
+    /// Inserts `separator` into a run of integer digits.
+    fn apply(self, digits: &str, separator: char) -> String {
+        let len = digits.len();
+        let mut out = String::with_capacity(len + len / 2);
+        for (i, digit) in digits.chars().enumerate() {
+            let remaining = len - i;
+            let boundary = match self {
+                Grouping::None => false,
+                Grouping::Thousands => remaining.is_multiple_of(3),
+                Grouping::Indian => {
+                    remaining == 3 || (remaining > 3 && (remaining - 3).is_multiple_of(2))
+                }
+            };
+            if i > 0 && boundary {
+                out.push(separator);
+            }
+            out.push(digit);
+        }
+        out
+    }
+}
+
+/// Digit grouping and separator characters used to format and parse localized numbers.
+///
+/// The default (no grouping, `.` decimal separator) matches `Display` and `FromStr`. The
+/// decimal and group separators should differ.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{Grouping, NumberStyle, SafeDec};
+///
+/// let eu = NumberStyle::new()
+///     .grouping(Grouping::Thousands)
+///     .decimal_sep(',')
+///     .group_sep('.');
+/// let amount = SafeDec::<2>::parse_with_style("1.234.567,89", eu).unwrap();
+/// assert_eq!(amount.to_string(), "1234567.89");
+/// assert_eq!(amount.format().style(eu).to_string(), "1.234.567,89");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberStyle {
+    grouping: Grouping,
+    decimal_sep: char,
+    group_sep: char,
+}
+
+impl Default for NumberStyle {
+    fn default() -> Self {
+        NumberStyle::new()
+    }
+}
+
+impl NumberStyle {
+    /// No grouping, `.` as the decimal separator and `,` as the (unused) group separator.
+    pub const fn new() -> Self {
+        NumberStyle {
+            grouping: Grouping::None,
+            decimal_sep: '.',
+            group_sep: ',',
+        }
+    }
+
+    /// Sets the digit grouping style.
+    pub const fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets the character written between the integer and fractional parts.
+    pub const fn decimal_sep(mut self, separator: char) -> Self {
+        self.decimal_sep = separator;
+        self
+    }
+
+    /// Sets the character written between digit groups.
+    pub const fn group_sep(mut self, separator: char) -> Self {
+        self.group_sep = separator;
+        self
+    }
+
+    /// Parses `s` at scale `D`, returning the raw scaled integer.
+    ///
+    /// Accepts an optional sign, surrounding whitespace and a missing fractional part. Group
+    /// separators are optional, but where present they must match the grouping style.
+    fn parse_raw<const D: usize>(self, s: &str) -> Result<SafeInt, ParseError> {
+        // Rewrite into the plain format, remembering where each kept character came from.
+        let mut normalized = String::with_capacity(s.len());
+        let mut positions = Vec::with_capacity(s.len());
+        let mut separators = Vec::new();
+        let mut groups = Vec::new();
+        let mut group_digits = 0;
+        let mut in_fraction = false;
+        for (index, c) in s.chars().enumerate() {
+            if !in_fraction && c == self.decimal_sep {
+                in_fraction = true;
+                normalized.push('.');
+            } else if !in_fraction && c == self.group_sep {
+                separators.push(index);
+                groups.push(group_digits);
+                group_digits = 0;
+                continue;
+            } else if c == '.' {
+                // A literal `.` that is not the decimal separator is not part of this style.
+                normalized.push('\u{fffd}');
+            } else {
+                if !in_fraction && c.is_ascii_digit() {
+                    group_digits += 1;
+                }
+                normalized.push(c);
+            }
+            positions.push(index);
+        }
+        groups.push(group_digits);
+
+        let options = ParseOptions {
+            optional_fraction: true,
+            allow_plus: true,
+            trim_whitespace: true,
+            ..ParseOptions::strict()
+        };
+        let raw = parsing::parse_safe_dec::<D>(&normalized, options).map_err(|err| {
+            let original =
+                |index: usize| positions.get(index).copied().unwrap_or(s.chars().count());
+            match err {
+                ParseError::MissingDigits { index } => ParseError::MissingDigits {
+                    index: original(index),
+                },
+                ParseError::InvalidDigit { index, .. } => ParseError::InvalidDigit {
+                    index: original(index),
+                    found: s.chars().nth(original(index)).unwrap_or('\u{fffd}'),
+                },
+                ParseError::MissingFractionalDigits { index } => {
+                    ParseError::MissingFractionalDigits {
+                        index: original(index),
+                    }
+                }
+                ParseError::TrailingCharacters { index } => ParseError::TrailingCharacters {
+                    index: original(index),
+                },
+                ParseError::ExponentOutOfRange { index } => ParseError::ExponentOutOfRange {
+                    index: original(index),
+                },
+                other => other,
+            }
+        })?;
+        if let Some(separator) = self.grouping.misplaced_separator(&groups) {
+            return Err(ParseError::MisplacedGroupSeparator {
+                index: separators[separator],
+            });
+        }
+        Ok(raw)
+    }
+}
+
+/// Builder returned by [`SafeInt::format`] and [`SafeDec::format`] that displays a value with
+/// a [`NumberStyle`].
+///
+/// Width, fill, alignment and sign flags are honored as in `Display`; a precision set with
+/// [`Formatted::precision`] or `{:.N}` rounds half to even.
+#[derive(Clone, Debug)]
+pub struct Formatted<'a> {
+    raw: &'a SafeInt,
+    scale: usize,
+    style: NumberStyle,
+    precision: Option<usize>,
+}
+
+impl<'a> Formatted<'a> {
+    fn new(raw: &'a SafeInt, scale: usize) -> Self {
+        Formatted {
+            raw,
+            scale,
+            style: NumberStyle::new(),
+            precision: None,
+        }
+    }
+
+    /// Replaces the whole [`NumberStyle`].
+    pub const fn style(mut self, style: NumberStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the digit grouping style.
+    pub const fn grouping(mut self, grouping: Grouping) -> Self {
+        self.style = self.style.grouping(grouping);
+        self
+    }
+
+    /// Sets the character written between the integer and fractional parts.
+    pub const fn decimal_sep(mut self, separator: char) -> Self {
+        self.style = self.style.decimal_sep(separator);
+        self
+    }
+
+    /// Sets the character written between digit groups.
+    pub const fn group_sep(mut self, separator: char) -> Self {
+        self.style = self.style.group_sep(separator);
+        self
+    }
+
+    /// Sets the number of fractional digits, rounding half to even or padding with zeros.
+    pub const fn precision(mut self, places: usize) -> Self {
+        self.precision = Some(places);
+        self
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let places = f.precision().or(self.precision).unwrap_or(self.scale);
+        let (non_negative, integer, fraction) = decimal_parts(self.raw, self.scale, places);
+        let mut body = self.style.grouping.apply(&integer, self.style.group_sep);
+        if places > 0 {
+            body.push(self.style.decimal_sep);
+            body.push_str(&fraction);
+        }
+        f.pad_integral(non_negative, "", &body)
+    }
+}
+
+impl SafeInt {
+    /// Returns a builder that displays the value with custom digit grouping.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::{Grouping, SafeInt};
+    ///
+    /// let value = SafeInt::from(12_345_678);
+    /// assert_eq!(value.format().grouping(Grouping::Thousands).to_string(), "12,345,678");
+    /// assert_eq!(value.format().grouping(Grouping::Indian).to_string(), "1,23,45,678");
+    /// ```
+    pub fn format(&self) -> Formatted<'_> {
+        Formatted::new(self, 0)
+    }
+
+    /// Parses an integer written with the given [`NumberStyle`], such as `"1.234.567"`.
+    pub fn parse_with_style(s: &str, style: NumberStyle) -> Result<SafeInt, ParseError> {
+        style.parse_raw::<0>(s)
+    }
+}
+
+impl<const D: usize> SafeDec<D> {
+    /// Returns a builder that displays the value with custom grouping and separators.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::{Grouping, SafeDec};
+    ///
+    /// let total: SafeDec<2> = "1234567.89".parse().unwrap();
+    /// let eu = total
+    ///     .format()
+    ///     .grouping(Grouping::Thousands)
+    ///     .decimal_sep(',')
+    ///     .group_sep('.');
+    /// assert_eq!(eu.to_string(), "1.234.567,89");
+    /// assert_eq!(format!("{:>14}", eu), "  1.234.567,89");
+    /// ```
+    pub fn format(&self) -> Formatted<'_> {
+        Formatted::new(self.raw(), D)
+    }
+
+    /// Parses a decimal written with the given [`NumberStyle`], such as `"1.234.567,89"`.
+    pub fn parse_with_style(s: &str, style: NumberStyle) -> Result<Self, ParseError> {
+        style.parse_raw::<D>(s).map(SafeDec::from_raw)
+    }
+}
+
+#[test]
+fn test_grouping_styles() {
+    let value: SafeDec<2> = "-1234567.89".parse().unwrap();
+    assert_eq!(value.format().to_string(), value.to_string());
+    assert_eq!(
+        value.format().grouping(Grouping::Thousands).to_string(),
+        "-1,234,567.89"
+    );
+    assert_eq!(
+        value.format().grouping(Grouping::Indian).to_string(),
+        "-12,34,567.89"
+    );
+    assert_eq!(
+        value
+            .format()
+            .grouping(Grouping::Thousands)
+            .group_sep('\u{a0}')
+            .decimal_sep(',')
+            .precision(1)
+            .to_string(),
+        "-1\u{a0}234\u{a0}567,9"
+    );
+    assert_eq!(
+        alloc::format!("{:+.0}", value.format().grouping(Grouping::Thousands)),
+        "-1,234,568"
+    );
+
+    let thousands = |n: u64| {
+        SafeInt::from(n)
+            .format()
+            .grouping(Grouping::Thousands)
+            .to_string()
+    };
+    let indian = |n: u64| {
+        SafeInt::from(n)
+            .format()
+            .grouping(Grouping::Indian)
+            .to_string()
+    };
+    assert_eq!(thousands(0), "0");
+    assert_eq!(thousands(999), "999");
+    assert_eq!(thousands(1_000), "1,000");
+    assert_eq!(thousands(100_000), "100,000");
+    assert_eq!(indian(999), "999");
+    assert_eq!(indian(1_000), "1,000");
+    assert_eq!(indian(100_000), "1,00,000");
+    assert_eq!(indian(10_000_000), "1,00,00,000");
+    assert_eq!(indian(123_456_789), "12,34,56,789");
+    assert_eq!(alloc::format!("{:.2}", SafeInt::from(5).format()), "5.00");
+}
+
+#[test]
+fn test_parse_with_style() {
+    let eu = NumberStyle::new()
+        .grouping(Grouping::Thousands)
+        .decimal_sep(',')
+        .group_sep('.');
+    let parse = |s: &str| SafeDec::<2>::parse_with_style(s, eu);
+    assert_eq!(
+        parse("1.234.567,89").unwrap(),
+        SafeDec::from_raw(123_456_789)
+    );
+    assert_eq!(parse("1234567,89").unwrap(), SafeDec::from_raw(123_456_789));
+    assert_eq!(parse(" -12,5 ").unwrap(), SafeDec::from_raw(-1_250));
+    assert_eq!(parse("+1.000").unwrap(), SafeDec::from_raw(100_000));
+    assert_eq!(
+        parse("1.23.567,89"),
+        Err(ParseError::MisplacedGroupSeparator { index: 4 })
+    );
+    assert_eq!(
+        parse("1234.567,89"),
+        Err(ParseError::MisplacedGroupSeparator { index: 4 })
+    );
+    assert_eq!(
+        parse(".123,00"),
+        Err(ParseError::MisplacedGroupSeparator { index: 0 })
+    );
+    assert_eq!(
+        parse("1..000"),
+        Err(ParseError::MisplacedGroupSeparator { index: 2 })
+    );
+    assert_eq!(
+        parse("1.234,5x"),
+        Err(ParseError::InvalidDigit {
+            index: 7,
+            found: 'x'
+        })
+    );
+    assert_eq!(
+        parse("1,234"),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 2,
+            actual: 3
+        })
+    );
+
+    let indian = NumberStyle::new().grouping(Grouping::Indian);
+    assert_eq!(
+        SafeInt::parse_with_style("12,34,56,789", indian).unwrap(),
+        123_456_789
+    );
+    assert_eq!(SafeInt::parse_with_style("1,234", indian).unwrap(), 1_234);
+    assert_eq!(
+        SafeInt::parse_with_style("1,234,567", indian),
+        Err(ParseError::MisplacedGroupSeparator { index: 5 })
+    );
+    assert_eq!(
+        SafeInt::parse_with_style("1,234", NumberStyle::new()),
+        Err(ParseError::MisplacedGroupSeparator { index: 1 })
+    );
+    assert_eq!(
+        SafeInt::parse_with_style("1.5", indian),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 0,
+            actual: 1
+        })
+    );
+
+    for text in ["0,00", "-9.876.543,21", "12,30"] {
+        let value = SafeDec::<2>::parse_with_style(text, eu).unwrap();
+        assert_eq!(value.format().style(eu).to_string(), text);
+    }
+}
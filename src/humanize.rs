@@ -0,0 +1,410 @@
+use core::fmt::Display;
+
+#[cfg(test)]
+use alloc::string::ToString;
+use alloc::{format, string::String};
+use num_bigint::BigUint;
+
+use crate::{RoundingMode, SafeDec, SafeInt, decimal::decimal_parts, integer::pow10};
+
+const SHORT_SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+const SI_PREFIXES: [&str; 11] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+const BINARY_PREFIXES: [&str; 9] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+const WORDS: [&str; 12] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+/// Style used by [`Humanized`] to abbreviate large magnitudes.
+///
+/// Values past the largest prefix keep using it with a longer mantissa.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// Short-scale letters with no space: `1.23K`, `4.5M`, `7B`, `12T`.
+    Short,
+    /// SI prefixes for powers of 1000, up to `Q` (10^30): `1.23 k`, `4.5 MB` with a unit.
+    Si,
+    /// IEC binary prefixes for powers of 1024, up to `Yi`: `4.5 GiB` with a unit.
+    Binary,
+    /// Scientific notation: `7.1e27`.
+    Scientific,
+    /// Short-scale English words up to decillion (10^33): `12.3 quadrillion`.
+    Words,
+}
+
+/// Builder returned by [`SafeInt::humanize`] and [`SafeDec::humanize`] that displays a value
+/// compactly in a given [`Notation`].
+///
+/// Values are rounded to three significant figures with [`RoundingMode::HalfEven`] by default,
+/// and trailing fractional zeros are dropped. All arithmetic is exact, so values of any size
+/// are supported. Width, fill and alignment flags are honored.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{Notation, RoundingMode, SafeInt};
+///
+/// let bytes = SafeInt::from(4_831_838_208u64);
+/// assert_eq!(bytes.humanize(Notation::Binary).unit("B").to_string(), "4.5 GiB");
+///
+/// let users = SafeInt::from(1_234_567);
+/// assert_eq!(users.humanize(Notation::Short).to_string(), "1.23M");
+/// assert_eq!(
+///     users
+///         .humanize(Notation::Short)
+///         .significant_figures(2)
+///         .rounding(RoundingMode::Ceil)
+///         .to_string(),
+///     "1.3M"
+/// );
+///
+/// let huge = SafeInt::from(71) * SafeInt::from(10).pow(26);
+/// assert_eq!(huge.humanize(Notation::Scientific).to_string(), "7.1e27");
+/// assert_eq!(huge.humanize(Notation::Words).to_string(), "7.1 octillion");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Humanized<'a> {
+    raw: &'a SafeInt,
+    scale: usize,
+    notation: Notation,
+    significant_figures: usize,
+    rounding: RoundingMode,
+    unit: &'a str,
+}
+
+impl<'a> Humanized<'a> {
+    fn new(raw: &'a SafeInt, scale: usize, notation: Notation) -> Self {
+        Humanized {
+            raw,
+            scale,
+            notation,
+            significant_figures: 3,
+            rounding: RoundingMode::HalfEven,
+            unit: "",
+        }
+    }
+
+    /// Sets the number of significant figures to keep (at least 1).
+    pub const fn significant_figures(mut self, figures: usize) -> Self {
+        self.significant_figures = if figures == 0 { 1 } else { figures };
+        self
+    }
+
+    /// Sets how the value is rounded to the significant figures.
+    pub const fn rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Sets a unit appended after the prefix, such as `B` or `Hz`. Only used by
+    /// [`Notation::Si`] and [`Notation::Binary`].
+    pub const fn unit(mut self, unit: &'a str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Rounds `numerator / denominator` to the configured significant figures, returning the
+    /// significand and its power-of-ten exponent.
+    fn round(&self, numerator: &SafeInt, denominator: &BigUint) -> (SafeInt, i64) {
+        let raw = numerator.big();
+        let magnitude = raw.magnitude();
+        if magnitude.bits() == 0 {
+            return (SafeInt::zero(), 0);
+        }
+        let figures = self.significant_figures as i64;
+        let exponent = floor_log10(magnitude, denominator) - figures + 1;
+        let ten = BigUint::from(10u8);
+        let (numerator, denominator) = if exponent >= 0 {
+            (numerator.clone(), denominator * ten.pow(exponent as u32))
+        } else {
+            (
+                numerator.clone()
+                    * SafeInt::from_big(ten.pow(exponent.unsigned_abs() as u32).into()),
+                denominator.clone(),
+            )
+        };
+        let significand = numerator
+            .div_round(&SafeInt::from_big(denominator.into()), self.rounding)
+            .unwrap_or_else(SafeInt::zero);
+        // Rounding up can carry into an extra digit, e.g. 9.99 -> 10.0.
+        if significand.big().magnitude()
+            >= &BigUint::from(10u8).pow(self.significant_figures as u32)
+        {
+            let carried = (significand / 10u8).unwrap_or_else(SafeInt::zero);
+            return (carried, exponent + 1);
+        }
+        (significand, exponent)
+    }
+
+    /// Writes `significand * 10^exponent` in plain decimal form without trailing zeros.
+    fn plain(significand: &SafeInt, exponent: i64) -> String {
+        if exponent >= 0 {
+            let shifted = significand * &*pow10(exponent as usize);
+            return format!("{shifted}");
+        }
+        let places = exponent.unsigned_abs() as usize;
+        let (non_negative, integer, fraction) = decimal_parts(significand, places, places);
+        let fraction = fraction.trim_end_matches('0');
+        let sign = if non_negative { "" } else { "-" };
+        if fraction.is_empty() {
+            format!("{sign}{integer}")
+        } else {
+            format!("{sign}{integer}.{fraction}")
+        }
+    }
+
+    fn render(&self) -> String {
+        let unscaled = BigUint::from(10u8).pow(self.scale as u32);
+        let (prefixes, step): (&[&str], u32) = match self.notation {
+            Notation::Scientific => {
+                let (significand, exponent) = self.round(self.raw, &unscaled);
+                let digits = significand.big().magnitude().to_str_radix(10);
+                let exponent = exponent + digits.len() as i64 - 1;
+                let mantissa = Self::plain(&significand, 1 - digits.len() as i64);
+                return format!("{mantissa}e{exponent}");
+            }
+            Notation::Binary => {
+                let value_bits = floor_log2(self.raw.big().magnitude(), &unscaled);
+                let max = BINARY_PREFIXES.len() - 1;
+                let mut power = (value_bits.max(0) / 10).min(max as i64) as usize;
+                // Whether `significand * 10^exponent` is at least `limit`.
+                let reaches = |significand: &SafeInt, exponent: i64, limit: u16| {
+                    let ten = BigUint::from(10u8);
+                    significand.big().magnitude() * ten.pow(exponent.max(0) as u32)
+                        >= BigUint::from(limit) * ten.pow((-exponent).max(0) as u32)
+                };
+                loop {
+                    let denominator = &unscaled << (10 * power);
+                    let (significand, exponent) = self.round(self.raw, &denominator);
+                    if power < max {
+                        // Rounding can reach 1024 of this prefix, and a value just below 1024
+                        // (which rounds to 1020 at three figures) already rounds to 1 of the
+                        // next prefix; either way, move up to the next one.
+                        let (next, next_exponent) = self.round(self.raw, &(&denominator << 10));
+                        if reaches(&significand, exponent, 1024) || reaches(&next, next_exponent, 1)
+                        {
+                            power += 1;
+                            continue;
+                        }
+                    }
+                    return self
+                        .with_prefix(Self::plain(&significand, exponent), BINARY_PREFIXES[power]);
+                }
+            }
+            Notation::Short => (&SHORT_SUFFIXES, 3),
+            Notation::Si => (&SI_PREFIXES, 3),
+            Notation::Words => (&WORDS, 3),
+        };
+        let (significand, exponent) = self.round(self.raw, &unscaled);
+        let digits = significand.big().magnitude().to_str_radix(10).len() as i64;
+        let magnitude = if significand.is_zero() {
+            0
+        } else {
+            exponent + digits - 1
+        };
+        let power = (magnitude.max(0) / step as i64).min(prefixes.len() as i64 - 1);
+        let mantissa = Self::plain(&significand, exponent - power * step as i64);
+        self.with_prefix(mantissa, prefixes[power as usize])
+    }
+
+    fn with_prefix(&self, mantissa: String, prefix: &str) -> String {
+        match self.notation {
+            Notation::Short => format!("{mantissa}{prefix}"),
+            Notation::Si | Notation::Binary if !prefix.is_empty() || !self.unit.is_empty() => {
+                format!("{mantissa} {prefix}{}", self.unit)
+            }
+            Notation::Words if !prefix.is_empty() => format!("{mantissa} {prefix}"),
+            _ => mantissa,
+        }
+    }
+}
+
+impl Display for Humanized<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.render())
+    }
+}
+
+/// `floor(log10(numerator / denominator))` for non-zero values.
+fn floor_log10(numerator: &BigUint, denominator: &BigUint) -> i64 {
+    let digits = |n: &BigUint| n.to_str_radix(10).len() as i64;
+    let mut exponent = digits(numerator) - digits(denominator);
+    let ten = BigUint::from(10u8);
+    let below = if exponent >= 0 {
+        numerator < &(denominator * ten.pow(exponent as u32))
+    } else {
+        &(numerator * ten.pow(exponent.unsigned_abs() as u32)) < denominator
+    };
+    if below {
+        exponent -= 1;
+    }
+    exponent
+}
+
+/// `floor(log2(numerator / denominator))`, or `i64::MIN` for zero.
+fn floor_log2(numerator: &BigUint, denominator: &BigUint) -> i64 {
+    if numerator.bits() == 0 {
+        return i64::MIN;
+    }
+    let mut exponent = numerator.bits() as i64 - denominator.bits() as i64;
+    let below = if exponent >= 0 {
+        numerator < &(denominator << exponent as usize)
+    } else {
+        &(numerator << exponent.unsigned_abs() as usize) < denominator
+    };
+    if below {
+        exponent -= 1;
+    }
+    exponent
+}
+
+impl SafeInt {
+    /// Returns a builder that displays the value compactly, such as `1.23M` or `4.5 GiB`.
+    pub fn humanize(&self, notation: Notation) -> Humanized<'_> {
+        Humanized::new(self, 0, notation)
+    }
+}
+
+impl<const D: usize> SafeDec<D> {
+    /// Returns a builder that displays the value compactly, such as `1.23M` or `12.3 quadrillion`.
+    pub fn humanize(&self, notation: Notation) -> Humanized<'_> {
+        Humanized::new(self.raw(), D, notation)
+    }
+}
+
+#[test]
+fn test_humanize_notations() {
+    let bytes = SafeInt::from(4_831_838_208u64);
+    assert_eq!(
+        bytes.humanize(Notation::Binary).unit("B").to_string(),
+        "4.5 GiB"
+    );
+    assert_eq!(
+        bytes.humanize(Notation::Si).unit("B").to_string(),
+        "4.83 GB"
+    );
+    assert_eq!(bytes.humanize(Notation::Short).to_string(), "4.83B");
+    assert_eq!(bytes.humanize(Notation::Words).to_string(), "4.83 billion");
+    assert_eq!(bytes.humanize(Notation::Scientific).to_string(), "4.83e9");
+
+    assert_eq!(
+        SafeInt::from(512)
+            .humanize(Notation::Binary)
+            .unit("B")
+            .to_string(),
+        "512 B"
+    );
+    assert_eq!(
+        SafeInt::from(512).humanize(Notation::Binary).to_string(),
+        "512"
+    );
+    assert_eq!(
+        SafeInt::from(999).humanize(Notation::Short).to_string(),
+        "999"
+    );
+    assert_eq!(SafeInt::zero().humanize(Notation::Short).to_string(), "0");
+    assert_eq!(
+        SafeInt::zero().humanize(Notation::Scientific).to_string(),
+        "0e0"
+    );
+    assert_eq!(
+        SafeInt::from(-1_500).humanize(Notation::Short).to_string(),
+        "-1.5K"
+    );
+
+    let quadrillions = SafeInt::from(12_345_678_901_234_567u64);
+    assert_eq!(
+        quadrillions.humanize(Notation::Words).to_string(),
+        "12.3 quadrillion"
+    );
+
+    // Values past the largest prefix keep it with a longer mantissa.
+    let huge = SafeInt::from(10).pow(40);
+    assert_eq!(
+        huge.humanize(Notation::Short).to_string(),
+        "10000000000000000000000000000T"
+    );
+    assert_eq!(huge.humanize(Notation::Si).to_string(), "10000000000 Q");
+    assert_eq!(huge.humanize(Notation::Scientific).to_string(), "1e40");
+}
+
+#[test]
+fn test_humanize_rounding() {
+    // Rounding that carries into the next prefix moves up to it.
+    let almost = SafeInt::from(999_999);
+    assert_eq!(almost.humanize(Notation::Short).to_string(), "1M");
+    assert_eq!(
+        almost
+            .humanize(Notation::Short)
+            .rounding(RoundingMode::Floor)
+            .to_string(),
+        "999K"
+    );
+    let almost_mib = SafeInt::from(1_048_575);
+    assert_eq!(almost_mib.humanize(Notation::Binary).to_string(), "1 Mi");
+    // 1023.99 Ki rounds to 1 Mi, while 1020 Ki is still shown in Ki.
+    let boundary: SafeDec<2> = "1048565.76".parse().unwrap();
+    assert_eq!(boundary.humanize(Notation::Binary).to_string(), "1 Mi");
+    assert_eq!(
+        SafeInt::from(1_044_480)
+            .humanize(Notation::Binary)
+            .to_string(),
+        "1020 Ki"
+    );
+    assert_eq!(
+        almost_mib
+            .humanize(Notation::Binary)
+            .significant_figures(4)
+            .to_string(),
+        "1 Mi"
+    );
+
+    let value = SafeInt::from(-1_250);
+    assert_eq!(
+        value
+            .humanize(Notation::Short)
+            .significant_figures(2)
+            .to_string(),
+        "-1.2K"
+    );
+    assert_eq!(
+        value
+            .humanize(Notation::Short)
+            .significant_figures(2)
+            .rounding(RoundingMode::Floor)
+            .to_string(),
+        "-1.3K"
+    );
+    assert_eq!(
+        value
+            .humanize(Notation::Short)
+            .significant_figures(0)
+            .to_string(),
+        "-1K"
+    );
+    assert_eq!(
+        SafeInt::from(123_456)
+            .humanize(Notation::Short)
+            .significant_figures(8)
+            .to_string(),
+        "123.456K"
+    );
+
+    let dec = SafeDec::<4>::from_raw(SafeInt::from(123));
+    assert_eq!(dec.humanize(Notation::Short).to_string(), "0.0123");
+    assert_eq!(dec.humanize(Notation::Scientific).to_string(), "1.23e-2");
+    let dec: SafeDec<2> = "7654321.99".parse().unwrap();
+    assert_eq!(
+        format!("{:>8}|", dec.humanize(Notation::Short)),
+        "   7.65M|"
+    );
+}
@@ -2,27 +2,33 @@ extern crate alloc;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec;
+use core::sync::atomic::{AtomicPtr, Ordering as AtomicOrdering};
 use core::{cmp::Ordering, fmt::Display, ops::*, str::FromStr};
 use num_bigint::{BigInt, BigUint, Sign};
 use num_integer::Integer;
-use num_traits::{One, Signed, ToPrimitive, Zero};
-use quoth::Parsable;
+use num_traits::{One, Signed, ToPrimitive, Zero, float::FloatCore};
 
 #[cfg(test)]
 use alloc::format;
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use lencode::dedupe::{DedupeDecoder, DedupeEncoder};
 #[cfg(test)]
 use lencode::io::Cursor;
 use lencode::io::{Error, Read, Write};
+use lencode::pack::Pack;
 use lencode::{Decode, Encode};
 #[cfg(test)]
 use std::time::{Duration, Instant};
 
-use crate::parsing::ParsedSafeInt;
+use crate::parsing::{self, ParseError};
+use crate::{backend, digits};
+pub use crate::{error::ConversionError, rounding::RoundingMode};
 
 /// Arbitrary-precision integer wrapper that exposes safe, non-panicking operations.
 ///
+/// Values that fit in an `i128` are stored inline and computed with overflow-checked machine
+/// arithmetic; only results outside that range allocate a `BigInt`.
+///
 /// # Examples
 /// Create values from primitives and perform safe division (returns `Option` to avoid panics):
 /// ```
@@ -34,9 +40,100 @@ use crate::parsing::ParsedSafeInt;
 /// assert_eq!(&a + &b, SafeInt::from(13));
 /// assert_eq!(SafeInt::from(5) / SafeInt::from(0), None);
 /// ```
-#[derive(Clone, Debug, Eq, Ord, Hash, Default, PartialEq, PartialOrd)]
+#[derive(Clone, Eq, Hash, Default, PartialEq)]
 #[repr(transparent)]
-pub struct SafeInt(BigInt);
+pub struct SafeInt(Repr);
+
+/// Storage behind [`SafeInt`]: values in the `i128` range stay inline and use overflow-checked
+/// machine arithmetic, and only larger magnitudes allocate a `BigInt`.
+///
+/// `Big` never holds a value that fits in an `i128`, so every value has exactly one
+/// representation and the derived `Eq` and `Hash` agree with numeric equality.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Small(i128),
+    Big(BigInt),
+}
+
+impl Default for Repr {
+    #[inline(always)]
+    fn default() -> Self {
+        Repr::Small(0)
+    }
+}
+
+impl ToPrimitive for Repr {
+    #[inline(always)]
+    fn to_i64(&self) -> Option<i64> {
+        match self {
+            Repr::Small(value) => i64::try_from(*value).ok(),
+            Repr::Big(value) => value.to_i64(),
+        }
+    }
+
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        match self {
+            Repr::Small(value) => u64::try_from(*value).ok(),
+            Repr::Big(value) => value.to_u64(),
+        }
+    }
+
+    #[inline(always)]
+    fn to_i128(&self) -> Option<i128> {
+        match self {
+            Repr::Small(value) => Some(*value),
+            Repr::Big(_) => None,
+        }
+    }
+
+    #[inline(always)]
+    fn to_u128(&self) -> Option<u128> {
+        match self {
+            Repr::Small(value) => u128::try_from(*value).ok(),
+            Repr::Big(value) => value.to_u128(),
+        }
+    }
+
+    #[inline(always)]
+    fn to_f64(&self) -> Option<f64> {
+        match self {
+            // Like `BigInt`, the cast rounds to nearest with ties to even.
+            Repr::Small(value) => Some(*value as f64),
+            Repr::Big(value) => value.to_f64(),
+        }
+    }
+}
+
+impl core::fmt::Debug for SafeInt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Repr::Small(value) => f.debug_tuple("SafeInt").field(value).finish(),
+            Repr::Big(value) => f.debug_tuple("SafeInt").field(value).finish(),
+        }
+    }
+}
+
+impl Ord for SafeInt {
+    fn cmp(&self, other: &SafeInt) -> Ordering {
+        match (&self.0, &other.0) {
+            (Repr::Small(a), Repr::Small(b)) => a.cmp(b),
+            (Repr::Big(a), Repr::Big(b)) => a.cmp(b),
+            // A `Big` value lies outside the `i128` range, so its sign decides.
+            (Repr::Big(a), Repr::Small(_)) if a.is_negative() => Ordering::Less,
+            (Repr::Big(_), Repr::Small(_)) => Ordering::Greater,
+            (Repr::Small(_), Repr::Big(b)) if b.is_negative() => Ordering::Greater,
+            (Repr::Small(_), Repr::Big(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for SafeInt {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
 /// Default iteration cap for the fixed-point approximation used by `pow_ratio_scaled` when
 /// large exponents require the fallback path.
@@ -44,29 +141,134 @@ pub const DEFAULT_MAX_ITERS: usize = 4_096;
 const MAX_EXACT_EXPONENT: u32 = 1_024;
 
 impl FromStr for SafeInt {
-    type Err = quoth::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut stream = quoth::ParseStream::from(s);
-        let parsed = ParsedSafeInt::parse(&mut stream)?;
-        Ok(parsed.value)
+        parsing::parse_safe_int(s)
     }
 }
 
+/// Honors width, fill, alignment, `+` and `0` flags. Like the primitive integers, the
+/// precision is ignored.
 impl Display for SafeInt {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.0)
+        match &self.0 {
+            Repr::Small(value) => {
+                // `u128::MAX` has 39 decimal digits, so inline values never allocate.
+                let mut buf = [0u8; 39];
+                let digits = small_digits(value.unsigned_abs(), &mut buf);
+                f.pad_integral(*value >= 0, "", digits)
+            }
+            Repr::Big(value) => {
+                let digits = digits::to_decimal(value.magnitude());
+                f.pad_integral(!self.is_negative(), "", &digits)
+            }
+        }
+    }
+}
+
+/// Writes the decimal digits of `value` into the end of `buf` and returns them.
+fn small_digits(mut value: u128, buf: &mut [u8; 39]) -> &str {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    core::str::from_utf8(&buf[start..]).unwrap_or_default()
+}
+
+macro_rules! impl_radix_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl core::fmt::$trait for SafeInt {
+                #[inline(always)]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$trait::fmt(&*self.big(), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_fmt!(LowerHex, UpperHex, Binary, Octal);
+
+impl SafeInt {
+    /// Writes `self / 10^scale` in scientific notation such as `1.25e-4`.
+    ///
+    /// Without a precision all significant digits are written and trailing zeros dropped. A
+    /// precision sets the number of mantissa digits after the point, rounding half to even.
+    pub(crate) fn fmt_exp(
+        &self,
+        scale: usize,
+        upper: bool,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let raw = self.big();
+        let magnitude = raw.magnitude();
+        let mut digits = digits::to_decimal(magnitude);
+        let mut exponent = digits.len() as i64 - 1 - scale as i64;
+        if magnitude.is_zero() {
+            exponent = 0;
+        }
+        match f.precision() {
+            Some(precision) if digits.len() > precision + 1 => {
+                let divisor = pow10(digits.len() - precision - 1);
+                let rounded = SafeInt::from_big(BigInt::from(magnitude.clone()))
+                    .div_round(&divisor, RoundingMode::HalfEven)
+                    .unwrap_or_else(SafeInt::zero);
+                digits = digits::to_decimal(rounded.big().magnitude());
+                if digits.len() > precision + 1 {
+                    // Rounding carried into a new leading digit, e.g. 9.99 -> 10.0.
+                    digits.truncate(precision + 1);
+                    exponent += 1;
+                }
+            }
+            Some(precision) => {
+                digits.extend(core::iter::repeat_n('0', precision + 1 - digits.len()))
+            }
+            None => {
+                let significant = digits.trim_end_matches('0').len().max(1);
+                digits.truncate(significant);
+            }
+        }
+        let mut body = String::with_capacity(digits.len() + 8);
+        body.push_str(&digits[..1]);
+        if digits.len() > 1 {
+            body.push('.');
+            body.push_str(&digits[1..]);
+        }
+        body.push(if upper { 'E' } else { 'e' });
+        body.push_str(&alloc::format!("{exponent}"));
+        f.pad_integral(!self.is_negative(), "", &body)
+    }
+}
+
+impl core::fmt::LowerExp for SafeInt {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_exp(0, false, f)
+    }
+}
+
+impl core::fmt::UpperExp for SafeInt {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_exp(0, true, f)
     }
 }
 
 impl SafeInt {
     /// Zero value.
     pub fn zero() -> SafeInt {
-        SafeInt(BigInt::zero())
+        SafeInt::small(0)
     }
     /// One value.
     pub fn one() -> SafeInt {
-        SafeInt(BigInt::one())
+        SafeInt::small(1)
     }
     /// Negative one value.
     pub fn neg_one() -> SafeInt {
@@ -77,64 +279,146 @@ impl SafeInt {
     /// Constant negative one value as a compile-time byte representation.
     pub const NEG_ONE: ConstSafeInt<2> = ConstSafeInt::from_bytes([1, 1]);
 
-    /// Returns the underlying `BigInt` reference.
+    /// Returns the value as a `BigInt`.
+    ///
+    /// Values in the `i128` range are stored inline, so for them the `BigInt` is built on
+    /// demand; larger values are borrowed. Before 0.5.0 this returned `&BigInt`. Inline values
+    /// have no `BigInt` to borrow, so the return type changed as a breaking change.
+    #[deprecated(
+        since = "0.5.0",
+        note = "ties callers to `num-bigint`; use `to_u64_digits` or `to_signed_bytes_le`"
+    )]
     #[inline(always)]
-    pub const fn raw(&self) -> &BigInt {
-        &self.0
+    pub fn raw(&self) -> Cow<'_, BigInt> {
+        self.big()
+    }
+
+    /// Constructs a `SafeInt` from a raw `BigInt`, storing it inline if it fits in an `i128`.
+    ///
+    /// Before 0.5.0 this was a `const fn`. Storing in-range values inline needs a conversion
+    /// that cannot run at compile time, so it no longer is.
+    #[deprecated(
+        since = "0.5.0",
+        note = "ties callers to `num-bigint`; use `from_u64_digits` or `from_signed_bytes_le`"
+    )]
+    #[inline(always)]
+    pub fn from_raw(value: BigInt) -> SafeInt {
+        SafeInt::from_big(value)
+    }
+
+    /// Wraps a `BigInt`, storing it inline if it fits in an `i128`.
+    #[inline(always)]
+    pub(crate) fn from_big(value: BigInt) -> SafeInt {
+        match value.to_i128() {
+            Some(small) => SafeInt::small(small),
+            None => SafeInt(Repr::Big(value)),
+        }
+    }
+
+    /// Returns the value as a `BigInt`: inline values are converted into a temporary, larger
+    /// values are borrowed.
+    #[inline(always)]
+    pub(crate) fn big(&self) -> Cow<'_, BigInt> {
+        match &self.0 {
+            Repr::Small(value) => Cow::Owned(BigInt::from(*value)),
+            Repr::Big(value) => Cow::Borrowed(value),
+        }
     }
 
-    /// Constructs a `SafeInt` from a raw `BigInt`.
+    /// Wraps a value that is stored inline.
     #[inline(always)]
-    pub const fn from_raw(value: BigInt) -> SafeInt {
-        SafeInt(value)
+    pub(crate) const fn small(value: i128) -> SafeInt {
+        SafeInt(Repr::Small(value))
+    }
+
+    /// Returns the inline value, or `None` if the value needs a `BigInt`.
+    #[inline(always)]
+    pub(crate) const fn as_small(&self) -> Option<i128> {
+        match self.0 {
+            Repr::Small(value) => Some(value),
+            Repr::Big(_) => None,
+        }
+    }
+
+    /// Consumes the value and returns it as a `BigInt`.
+    #[inline(always)]
+    pub(crate) fn into_raw(self) -> BigInt {
+        match self.0 {
+            Repr::Small(value) => BigInt::from(value),
+            Repr::Big(value) => value,
+        }
+    }
+
+    /// Applies `op` to the value as a `BigInt`, then stores the result inline again if it fits.
+    #[inline(always)]
+    fn update_raw(&mut self, op: impl FnOnce(&mut BigInt)) {
+        let mut value = core::mem::take(self).into_raw();
+        op(&mut value);
+        *self = SafeInt::from_big(value);
     }
 
     /// Returns `true` if the value is negative.
     #[inline(always)]
     pub fn is_negative(&self) -> bool {
-        self.0.sign() == Sign::Minus
+        match &self.0 {
+            Repr::Small(value) => *value < 0,
+            Repr::Big(value) => value.sign() == Sign::Minus,
+        }
     }
 
     /// Returns `true` if the value is evenly divisible by 2.
     #[inline(always)]
     pub fn is_even(&self) -> bool {
-        self.0.is_even()
+        match &self.0 {
+            Repr::Small(value) => value % 2 == 0,
+            Repr::Big(value) => value.is_even(),
+        }
     }
 
     /// Returns `true` if the value is not evenly divisible by 2.
     #[inline(always)]
     pub fn is_odd(&self) -> bool {
-        self.0.is_odd()
+        !self.is_even()
     }
 
     /// Returns `true` if the value is exactly zero.
     #[inline(always)]
     pub fn is_zero(&self) -> bool {
-        self.0.is_zero()
+        matches!(self.0, Repr::Small(0))
     }
 
     /// Returns the absolute value.
     #[inline(always)]
     pub fn abs(self) -> SafeInt {
-        SafeInt(self.0.abs())
+        match self.as_small().and_then(i128::checked_abs) {
+            Some(value) => SafeInt::small(value),
+            None => SafeInt::from_big(self.into_raw().abs()),
+        }
     }
 
     /// Raises the number to an unsigned integer power.
     #[inline(always)]
     pub fn pow(self, exp: u32) -> SafeInt {
-        SafeInt(self.0.pow(exp))
+        match self.as_small().and_then(|value| value.checked_pow(exp)) {
+            Some(value) => SafeInt::small(value),
+            None => SafeInt::from_big(backend::pow(&self.big(), exp)),
+        }
     }
 
     /// Computes quotient and remainder simultaneously.
     /// Returns `None` if `other` is zero.
     #[inline(always)]
     pub fn div_rem(self, other: SafeInt) -> Option<(SafeInt, SafeInt)> {
-        if other.0.is_zero() {
-            None
-        } else {
-            let (div, rem) = self.0.div_rem(&other.0);
-            Some((SafeInt(div), SafeInt(rem)))
+        if other.is_zero() {
+            return None;
+        }
+        if let (Some(a), Some(b)) = (self.as_small(), other.as_small())
+            && let Some(quotient) = a.checked_div(b)
+        {
+            return Some((SafeInt::small(quotient), SafeInt::small(a % b)));
         }
+        let (div, rem) = backend::div_rem(&self.big(), &other.big());
+        Some((SafeInt::from_big(div), SafeInt::from_big(rem)))
     }
 
     /// Converts to `u8` if the value fits.
@@ -209,6 +493,295 @@ impl SafeInt {
         self.0.to_isize()
     }
 
+    /// Converts to the nearest `f64` (ties to even). Magnitudes beyond `f64::MAX` saturate to
+    /// positive or negative infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from(-42).to_f64(), -42.0);
+    /// assert_eq!(SafeInt::from((1u64 << 53) + 1).to_f64(), 9_007_199_254_740_992.0);
+    /// assert_eq!(SafeInt::from(10).pow(400).to_f64(), f64::INFINITY);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(if self.is_negative() {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        })
+    }
+
+    /// Converts an integral `f64` exactly. Returns `None` for NaN, infinities, and values with a
+    /// fractional part.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from_f64_exact(-3.0).unwrap(), -3);
+    /// assert_eq!(SafeInt::from_f64_exact(1e20).unwrap(), 100_000_000_000_000_000_000u128);
+    /// assert_eq!(SafeInt::from_f64_exact(2.5), None);
+    /// assert_eq!(SafeInt::from_f64_exact(f64::NAN), None);
+    /// ```
+    pub fn from_f64_exact(value: f64) -> Option<SafeInt> {
+        if !value.is_finite() {
+            return None;
+        }
+        let (mantissa, exponent, sign) = FloatCore::integer_decode(value);
+        let magnitude = if exponent >= 0 {
+            BigInt::from(mantissa) << exponent as usize
+        } else {
+            let shift = exponent.unsigned_abs() as u32;
+            if mantissa != 0 && mantissa.trailing_zeros() < shift {
+                return None;
+            }
+            BigInt::from(mantissa.checked_shr(shift).unwrap_or(0))
+        };
+        Some(SafeInt::from_big(if sign < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }))
+    }
+
+    /// Parses an integer written in `radix` (2 through 36) with an optional leading `-`.
+    ///
+    /// Letters are case-insensitive and no base prefix is accepted; use [`FromStr`] for
+    /// `0x`/`0o`/`0b` prefixed literals.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from_str_radix("-ff", 16).unwrap(), -255);
+    /// assert_eq!(SafeInt::from_str_radix("z", 36).unwrap(), 35);
+    /// assert!(SafeInt::from_str_radix("12", 1).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<SafeInt, ParseError> {
+        parsing::parse_safe_int_radix(s, radix)
+    }
+
+    /// Formats the value in `radix` (2 through 36) using lowercase letters.
+    ///
+    /// Returns `None` if the radix is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from(-255).to_str_radix(16).unwrap(), "-ff");
+    /// assert_eq!(SafeInt::from(5).to_str_radix(2).unwrap(), "101");
+    /// assert_eq!(SafeInt::from(5).to_str_radix(37), None);
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> Option<String> {
+        (2..=36).contains(&radix).then(|| match radix {
+            10 => alloc::format!("{self}"),
+            _ => self.big().to_str_radix(radix),
+        })
+    }
+
+    /// Returns whether the value is negative and the little-endian 64-bit limbs of its
+    /// magnitude, without trailing zero limbs (zero has none).
+    ///
+    /// Together with [`SafeInt::from_u64_digits`] this moves values in and out of any
+    /// big-integer library without depending on the one `SafeInt` uses internally.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from(-5).to_u64_digits(), (true, vec![5]));
+    /// assert_eq!(SafeInt::from(1u128 << 64).to_u64_digits(), (false, vec![0, 1]));
+    /// assert_eq!(SafeInt::zero().to_u64_digits(), (false, vec![]));
+    /// ```
+    pub fn to_u64_digits(&self) -> (bool, Vec<u64>) {
+        let digits = match &self.0 {
+            Repr::Small(value) => {
+                let magnitude = value.unsigned_abs();
+                let mut digits = Vec::with_capacity(2);
+                digits.push(magnitude as u64);
+                digits.push((magnitude >> 64) as u64);
+                while digits.last() == Some(&0) {
+                    digits.pop();
+                }
+                digits
+            }
+            Repr::Big(value) => value.magnitude().to_u64_digits(),
+        };
+        (self.is_negative(), digits)
+    }
+
+    /// Builds a value from a sign and little-endian 64-bit magnitude limbs, as returned by
+    /// [`SafeInt::to_u64_digits`]. Trailing zero limbs are allowed, and zero is never negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::from_u64_digits(true, &[5]), -5);
+    /// assert_eq!(SafeInt::from_u64_digits(false, &[0, 1, 0]), 1u128 << 64);
+    /// assert!(SafeInt::from_u64_digits(true, &[]).is_zero());
+    /// ```
+    pub fn from_u64_digits(negative: bool, digits: &[u64]) -> SafeInt {
+        let used = digits
+            .iter()
+            .rposition(|&digit| digit != 0)
+            .map_or(0, |last| last + 1);
+        let digits = &digits[..used];
+        if digits.len() <= 2 {
+            let magnitude = digits
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &digit| (acc << 64) | u128::from(digit));
+            let value = if negative {
+                0i128.checked_sub_unsigned(magnitude)
+            } else {
+                i128::try_from(magnitude).ok()
+            };
+            if let Some(value) = value {
+                return SafeInt::small(value);
+            }
+        }
+        let magnitude = BigUint::from_slice(
+            &digits
+                .iter()
+                .flat_map(|&digit| [digit as u32, (digit >> 32) as u32])
+                .collect::<Vec<_>>(),
+        );
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        SafeInt::from_big(BigInt::from_biguint(sign, magnitude))
+    }
+
+    /// Returns the little-endian bytes of the magnitude, or `None` if the value is negative.
+    ///
+    /// Zero is encoded as `[0]`.
+    #[inline(always)]
+    pub fn to_bytes_le(&self) -> Option<Vec<u8>> {
+        (!self.is_negative()).then(|| self.big().magnitude().to_bytes_le())
+    }
+
+    /// Returns the big-endian bytes of the magnitude, or `None` if the value is negative.
+    ///
+    /// Zero is encoded as `[0]`.
+    #[inline(always)]
+    pub fn to_bytes_be(&self) -> Option<Vec<u8>> {
+        (!self.is_negative()).then(|| self.big().magnitude().to_bytes_be())
+    }
+
+    /// Returns the shortest little-endian two's-complement representation.
+    #[inline(always)]
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        self.big().to_signed_bytes_le()
+    }
+
+    /// Returns the shortest big-endian two's-complement representation.
+    #[inline(always)]
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        self.big().to_signed_bytes_be()
+    }
+
+    /// Interprets `bytes` as an unsigned little-endian integer.
+    #[inline(always)]
+    pub fn from_bytes_le(bytes: &[u8]) -> SafeInt {
+        SafeInt::from_big(BigInt::from_bytes_le(Sign::Plus, bytes))
+    }
+
+    /// Interprets `bytes` as an unsigned big-endian integer.
+    #[inline(always)]
+    pub fn from_bytes_be(bytes: &[u8]) -> SafeInt {
+        SafeInt::from_big(BigInt::from_bytes_be(Sign::Plus, bytes))
+    }
+
+    /// Interprets `bytes` as a little-endian two's-complement integer.
+    #[inline(always)]
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> SafeInt {
+        SafeInt::from_big(BigInt::from_signed_bytes_le(bytes))
+    }
+
+    /// Interprets `bytes` as a big-endian two's-complement integer.
+    #[inline(always)]
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> SafeInt {
+        SafeInt::from_big(BigInt::from_signed_bytes_be(bytes))
+    }
+
+    /// Writes the value as an `N`-byte unsigned little-endian word, zero-extended.
+    ///
+    /// Returns `None` if the value is negative or needs more than `N` bytes.
+    pub fn to_le_bytes_fixed<const N: usize>(&self) -> Option<[u8; N]> {
+        let mut out = self.to_be_bytes_fixed::<N>()?;
+        out.reverse();
+        Some(out)
+    }
+
+    /// Writes the value as an `N`-byte unsigned big-endian word, zero-extended.
+    ///
+    /// Returns `None` if the value is negative or needs more than `N` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// // EVM uint256 word
+    /// let word = SafeInt::from(0x1234).to_be_bytes_fixed::<32>().unwrap();
+    /// assert_eq!(&word[30..], &[0x12, 0x34]);
+    /// assert_eq!(SafeInt::from_bytes_be(&word), 0x1234);
+    ///
+    /// assert_eq!(SafeInt::from(256).to_be_bytes_fixed::<1>(), None);
+    /// assert_eq!(SafeInt::from(-1).to_be_bytes_fixed::<32>(), None);
+    /// ```
+    pub fn to_be_bytes_fixed<const N: usize>(&self) -> Option<[u8; N]> {
+        let mut out = [0u8; N];
+        if self.is_zero() {
+            return Some(out);
+        }
+        let bytes = self.to_bytes_be()?;
+        if bytes.len() > N {
+            return None;
+        }
+        out[N - bytes.len()..].copy_from_slice(&bytes);
+        Some(out)
+    }
+
+    /// Writes the value as an `N`-byte two's-complement little-endian word, sign-extended.
+    ///
+    /// Returns `None` if the value does not fit in `N` signed bytes.
+    pub fn to_signed_le_bytes_fixed<const N: usize>(&self) -> Option<[u8; N]> {
+        let mut out = self.to_signed_be_bytes_fixed::<N>()?;
+        out.reverse();
+        Some(out)
+    }
+
+    /// Writes the value as an `N`-byte two's-complement big-endian word, sign-extended.
+    ///
+    /// Returns `None` if the value does not fit in `N` signed bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// // EVM int256 word
+    /// let word = SafeInt::from(-2).to_signed_be_bytes_fixed::<32>().unwrap();
+    /// assert!(word[..31].iter().all(|&b| b == 0xFF));
+    /// assert_eq!(word[31], 0xFE);
+    /// assert_eq!(SafeInt::from_signed_bytes_be(&word), -2);
+    ///
+    /// assert_eq!(SafeInt::from(128).to_signed_be_bytes_fixed::<1>(), None);
+    /// ```
+    pub fn to_signed_be_bytes_fixed<const N: usize>(&self) -> Option<[u8; N]> {
+        let fill = if self.is_negative() { 0xFF } else { 0x00 };
+        let mut out = [fill; N];
+        if self.is_zero() {
+            return Some(out);
+        }
+        let bytes = self.to_signed_bytes_be();
+        if bytes.len() > N {
+            return None;
+        }
+        out[N - bytes.len()..].copy_from_slice(&bytes);
+        Some(out)
+    }
+
     /// Performs integer ceiling division (`self / b`, rounded up).
     #[inline(always)]
     pub fn ceil_div(&self, b: SafeInt) -> Option<SafeInt> {
@@ -216,6 +789,57 @@ impl SafeInt {
         Some(((self - one.clone()) / b)? + one)
     }
 
+    /// Divides by `other`, rounding the quotient with the given [`RoundingMode`].
+    /// Returns `None` if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::{RoundingMode, SafeInt};
+    ///
+    /// let seven = SafeInt::from(7);
+    /// let two = SafeInt::from(2);
+    /// assert_eq!(seven.div_round(&two, RoundingMode::TowardZero).unwrap(), 3);
+    /// assert_eq!(seven.div_round(&two, RoundingMode::HalfEven).unwrap(), 4);
+    /// assert_eq!((-seven).div_round(&two, RoundingMode::Floor).unwrap(), -4);
+    /// ```
+    pub fn div_round(&self, other: &SafeInt, mode: RoundingMode) -> Option<SafeInt> {
+        if other.is_zero() {
+            return None;
+        }
+        let negative = self.is_negative() != other.is_negative();
+        if let (Some(a), Some(b)) = (self.as_small(), other.as_small())
+            && let Some(quotient) = a.checked_div(b)
+        {
+            let remainder = a % b;
+            if remainder == 0 {
+                return Some(SafeInt::small(quotient));
+            }
+            // A non-zero remainder means `|b| >= 2`, so the quotient can move by one and the
+            // doubled remainder stays below `2 * |b| <= 2^128`.
+            let half = || (remainder.unsigned_abs() << 1).cmp(&b.unsigned_abs());
+            let step = match mode.rounds_away(negative, half, quotient % 2 != 0) {
+                false => 0,
+                true if negative => -1,
+                true => 1,
+            };
+            return Some(SafeInt::small(quotient + step));
+        }
+        let divisor = other.big();
+        let (mut quotient, remainder) = backend::div_rem(&self.big(), &divisor);
+        if remainder.is_zero() {
+            return Some(SafeInt::from_big(quotient));
+        }
+        let half = || (remainder.magnitude() << 1usize).cmp(divisor.magnitude());
+        if mode.rounds_away(negative, half, quotient.is_odd()) {
+            if negative {
+                quotient -= 1;
+            } else {
+                quotient += 1;
+            }
+        }
+        Some(SafeInt::from_big(quotient))
+    }
+
     /// Computes `(base_numerator / base_denominator)^(exponent_numerator / exponent_denominator)`
     /// scaled by the provided factor. Returns `None` if the base or exponent denominator is zero
     /// or if the base is non-positive. Uses an exact integer path when the exponent fits in 32
@@ -285,10 +909,10 @@ impl SafeInt {
             return None;
         }
 
-        let base_num = base_numerator.0.to_biguint()?;
-        let base_den = base_denominator.0.to_biguint()?;
-        let mut exp_num = exponent_numerator.0.to_biguint()?;
-        let mut exp_den = exponent_denominator.0.to_biguint()?;
+        let base_num = base_numerator.big().to_biguint()?;
+        let base_den = base_denominator.big().to_biguint()?;
+        let mut exp_num = exponent_numerator.big().to_biguint()?;
+        let mut exp_den = exponent_denominator.big().to_biguint()?;
 
         if exp_num.is_zero() {
             return Some(scale.clone());
@@ -300,7 +924,7 @@ impl SafeInt {
             exp_den /= g;
         }
 
-        let scale_abs = scale.0.to_biguint()?;
+        let scale_abs = scale.big().to_biguint()?;
         let scale_bits = u32::try_from(scale_abs.bits()).unwrap_or(u32::MAX);
 
         let exp_num_bits = exp_num.bits();
@@ -321,7 +945,7 @@ impl SafeInt {
                 let target_den = base_den_pow;
 
                 let root = nth_root_ratio_floor(&target_num, &target_den, exp_den_u32);
-                return Some(SafeInt(BigInt::from_biguint(Sign::Plus, root)));
+                return Some(SafeInt::from_big(BigInt::from_biguint(Sign::Plus, root)));
             }
         }
 
@@ -382,7 +1006,7 @@ impl SafeInt {
         let result =
             (exp_requested * BigInt::from_biguint(Sign::Plus, scale_abs)).div_floor(&target_scale);
 
-        Some(SafeInt(result))
+        Some(SafeInt::from_big(result))
     }
 
     /// Exponentiate base to exponent. Base can be large integer number betwen 0 and u64::MAX
@@ -434,10 +1058,10 @@ impl SafeInt {
         }
 
         // base is an integer; convert to BigUint
-        let base_uint = base.0.to_biguint()?;
+        let base_uint = base.big().to_biguint()?;
 
-        let mut exp_num = exponent_numerator.0.to_biguint()?;
-        let mut exp_den = exponent_denominator.0.to_biguint()?;
+        let mut exp_num = exponent_numerator.big().to_biguint()?;
+        let mut exp_den = exponent_denominator.big().to_biguint()?;
 
         if exp_num.is_zero() {
             // base^0 ~= 1, scaled by `scale`
@@ -451,7 +1075,7 @@ impl SafeInt {
             exp_den /= g;
         }
 
-        let scale_abs = scale.0.to_biguint()?;
+        let scale_abs = scale.big().to_biguint()?;
         let scale_bits = u32::try_from(scale_abs.bits()).unwrap_or(u32::MAX);
 
         // ---- Fast path: small rational exponent, exact pow/root on integers ----
@@ -478,7 +1102,7 @@ impl SafeInt {
                 let target_den = BigUint::one();
 
                 let root = nth_root_ratio_floor(&target_num, &target_den, exp_den_u32);
-                return Some(SafeInt(BigInt::from_biguint(Sign::Plus, root)));
+                return Some(SafeInt::from_big(BigInt::from_biguint(Sign::Plus, root)));
             }
         }
 
@@ -533,7 +1157,7 @@ impl SafeInt {
         let result =
             (exp_requested * BigInt::from_biguint(Sign::Plus, scale_abs)).div_floor(&target_scale);
 
-        Some(SafeInt(result))
+        Some(SafeInt::from_big(result))
     }
 
     /// Calculates integer part of log10 of this SafeInt
@@ -549,7 +1173,7 @@ impl SafeInt {
         } else if *self < 10 {
             Some(SafeInt::from(0))
         } else {
-            let scale_abs = scale.0.to_biguint()?;
+            let scale_abs = scale.big().to_biguint()?;
             let scale_bits = u32::try_from(scale_abs.bits()).unwrap_or(u32::MAX);
             let requested_precision = precision.max(32).max(scale_bits.saturating_add(8));
             let guard_bits: u32 = 24;
@@ -569,7 +1193,7 @@ impl SafeInt {
             );
             let ln_two = -ln_half;
 
-            let value_uint = self.0.to_biguint()?;
+            let value_uint = self.big().to_biguint()?;
             let ln_value = ln_biguint(
                 &value_uint,
                 internal_precision,
@@ -591,13 +1215,174 @@ impl SafeInt {
             );
 
             // Divide ln_value by ln_ten
-            let ln_value = SafeInt::from(ln_value);
-            let ln_ten = SafeInt::from(ln_ten);
+            let ln_value = SafeInt::from_big(ln_value);
+            let ln_ten = SafeInt::from_big(ln_ten);
             ln_value / ln_ten
         }
     }
 }
 
+impl SafeInt {
+    /// Low 128 bits of the two's complement representation.
+    fn low_u128_wrapping(&self) -> u128 {
+        let value = match &self.0 {
+            Repr::Small(value) => return *value as u128,
+            Repr::Big(value) => value,
+        };
+        let mut digits = value.magnitude().iter_u64_digits();
+        let low = u128::from(digits.next().unwrap_or(0));
+        let high = u128::from(digits.next().unwrap_or(0));
+        let bits = low | (high << 64);
+        if self.is_negative() {
+            bits.wrapping_neg()
+        } else {
+            bits
+        }
+    }
+}
+
+macro_rules! impl_primitive_conversions {
+    ($($prim:ident => $checked:ident, $saturating:ident, $wrapping:ident);* $(;)?) => {
+        impl SafeInt {
+            $(
+                #[doc = concat!(
+                    "Converts to `", stringify!($prim), "`, clamping to `",
+                    stringify!($prim), "::MIN` or `", stringify!($prim), "::MAX` when out of range."
+                )]
+                #[inline(always)]
+                pub fn $saturating(&self) -> $prim {
+                    self.0.$checked().unwrap_or(if self.is_negative() {
+                        $prim::MIN
+                    } else {
+                        $prim::MAX
+                    })
+                }
+
+                #[doc = concat!(
+                    "Converts to `", stringify!($prim), "`, keeping only the low bits of the ",
+                    "two's complement representation (like an `as` cast)."
+                )]
+                #[inline(always)]
+                pub fn $wrapping(&self) -> $prim {
+                    self.low_u128_wrapping() as $prim
+                }
+            )*
+        }
+
+        $(
+            impl TryFrom<&SafeInt> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeInt) -> Result<$prim, ConversionError> {
+                    value.0.$checked().ok_or(if value.is_negative() {
+                        ConversionError::Underflow {
+                            target: stringify!($prim),
+                        }
+                    } else {
+                        ConversionError::Overflow {
+                            target: stringify!($prim),
+                        }
+                    })
+                }
+            }
+
+            impl TryFrom<SafeInt> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeInt) -> Result<$prim, ConversionError> {
+                    $prim::try_from(&value)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_conversions!(
+    u8 => to_u8, to_u8_saturating, to_u8_wrapping;
+    u16 => to_u16, to_u16_saturating, to_u16_wrapping;
+    u32 => to_u32, to_u32_saturating, to_u32_wrapping;
+    u64 => to_u64, to_u64_saturating, to_u64_wrapping;
+    u128 => to_u128, to_u128_saturating, to_u128_wrapping;
+    usize => to_usize, to_usize_saturating, to_usize_wrapping;
+    i8 => to_i8, to_i8_saturating, to_i8_wrapping;
+    i16 => to_i16, to_i16_saturating, to_i16_wrapping;
+    i32 => to_i32, to_i32_saturating, to_i32_wrapping;
+    i64 => to_i64, to_i64_saturating, to_i64_wrapping;
+    i128 => to_i128, to_i128_saturating, to_i128_wrapping;
+    isize => to_isize, to_isize_saturating, to_isize_wrapping;
+);
+
+/// Exponents below this are served from the power-of-ten cache.
+const CACHED_POW10: usize = 128;
+
+/// Returns the table held by `cell`, building it with `init` on first use.
+///
+/// This works without `std`: threads that race on the first call each build a table, one of
+/// them is published and the others are dropped. The published table is never freed.
+fn lazy_table<T>(cell: &'static AtomicPtr<Vec<T>>, init: impl FnOnce() -> Vec<T>) -> &'static [T] {
+    let mut table = cell.load(AtomicOrdering::Acquire);
+    if table.is_null() {
+        let built = Box::into_raw(Box::new(init()));
+        table = match cell.compare_exchange(
+            core::ptr::null_mut(),
+            built,
+            AtomicOrdering::AcqRel,
+            AtomicOrdering::Acquire,
+        ) {
+            Ok(_) => built,
+            Err(published) => {
+                // SAFETY: `built` came from `Box::into_raw` above and was never published.
+                drop(unsafe { Box::from_raw(built) });
+                published
+            }
+        };
+    }
+    // SAFETY: a published table is never freed or mutated, so it lives for `'static`.
+    unsafe { &*table }
+}
+
+/// Returns `10^exp`, borrowed from a table built on first use for exponents below
+/// [`CACHED_POW10`].
+pub(crate) fn pow10(exp: usize) -> Cow<'static, SafeInt> {
+    static TABLE: AtomicPtr<Vec<SafeInt>> = AtomicPtr::new(core::ptr::null_mut());
+    if exp >= CACHED_POW10 {
+        return Cow::Owned(SafeInt::from(10).pow(exp as u32));
+    }
+    let table = lazy_table(&TABLE, || {
+        let mut powers = Vec::with_capacity(CACHED_POW10);
+        let mut power = SafeInt::one();
+        for _ in 0..CACHED_POW10 {
+            let next = &power * 10u8;
+            powers.push(power);
+            power = next;
+        }
+        powers
+    });
+    Cow::Borrowed(&table[exp])
+}
+
+/// Returns `10^exp` as a `BigUint`, borrowed from its own table for exponents below
+/// [`CACHED_POW10`] so unsigned decimals never round-trip through `SafeInt`.
+pub(crate) fn pow10_unsigned(exp: usize) -> Cow<'static, BigUint> {
+    static TABLE: AtomicPtr<Vec<BigUint>> = AtomicPtr::new(core::ptr::null_mut());
+    if exp >= CACHED_POW10 {
+        return Cow::Owned(BigUint::from(10u8).pow(exp as u32));
+    }
+    let table = lazy_table(&TABLE, || {
+        let mut powers = Vec::with_capacity(CACHED_POW10);
+        let mut power = BigUint::one();
+        for _ in 0..CACHED_POW10 {
+            let next = &power * 10u8;
+            powers.push(power);
+            power = next;
+        }
+        powers
+    });
+    Cow::Borrowed(&table[exp])
+}
+
 fn gcd_biguint(mut a: BigUint, mut b: BigUint) -> BigUint {
     while !b.is_zero() {
         let r = &a % &b;
@@ -741,7 +1526,10 @@ impl Neg for SafeInt {
 
     #[inline(always)]
     fn neg(self) -> SafeInt {
-        SafeInt(-self.0)
+        match self.as_small().and_then(i128::checked_neg) {
+            Some(value) => SafeInt::small(value),
+            None => SafeInt::from_big(-self.into_raw()),
+        }
     }
 }
 
@@ -750,18 +1538,33 @@ impl Neg for &SafeInt {
 
     #[inline(always)]
     fn neg(self) -> SafeInt {
-        SafeInt(-self.0.clone())
+        match self.as_small().and_then(i128::checked_neg) {
+            Some(value) => SafeInt::small(value),
+            None => SafeInt::from_big(-&*self.big()),
+        }
     }
 }
 
+/// Applies `$small`, an overflow-checked `i128` operation, when both operands are stored
+/// inline. Yields `None` if either operand is a `BigInt` or the result does not fit, in which
+/// case the caller falls back to `BigInt` arithmetic.
+macro_rules! small_op {
+    ($lhs:expr, $rhs:expr, $small:expr) => {
+        match ($lhs, $rhs) {
+            (Some(a), Some(b)) => $small(a, b).map(SafeInt::small),
+            _ => None,
+        }
+    };
+}
+
 macro_rules! impl_pair_ops {
-    ($trait:ident, $method:ident) => {
+    ($trait:ident, $method:ident, $small:expr, $big:path) => {
         impl $trait for SafeInt {
             type Output = SafeInt;
 
             #[inline(always)]
             fn $method(self, other: SafeInt) -> SafeInt {
-                SafeInt(self.0.$method(other.0))
+                (&self).$method(&other)
             }
         }
 
@@ -770,7 +1573,7 @@ macro_rules! impl_pair_ops {
 
             #[inline(always)]
             fn $method(self, other: &SafeInt) -> SafeInt {
-                SafeInt(self.0.$method(&other.0))
+                (&self).$method(other)
             }
         }
 
@@ -779,7 +1582,7 @@ macro_rules! impl_pair_ops {
 
             #[inline(always)]
             fn $method(self, other: SafeInt) -> SafeInt {
-                SafeInt(self.0.clone().$method(other.0))
+                self.$method(&other)
             }
         }
 
@@ -788,7 +1591,49 @@ macro_rules! impl_pair_ops {
 
             #[inline(always)]
             fn $method(self, other: &SafeInt) -> SafeInt {
-                SafeInt(self.0.clone().$method(&other.0))
+                small_op!(self.as_small(), other.as_small(), $small)
+                    .unwrap_or_else(|| SafeInt::from_big($big(&self.big(), &other.big())))
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $small:expr) => {
+        impl $trait for SafeInt {
+            type Output = SafeInt;
+
+            #[inline(always)]
+            fn $method(self, other: SafeInt) -> SafeInt {
+                small_op!(self.as_small(), other.as_small(), $small)
+                    .unwrap_or_else(|| SafeInt::from_big(self.into_raw().$method(other.into_raw())))
+            }
+        }
+
+        impl $trait<&SafeInt> for SafeInt {
+            type Output = SafeInt;
+
+            #[inline(always)]
+            fn $method(self, other: &SafeInt) -> SafeInt {
+                small_op!(self.as_small(), other.as_small(), $small)
+                    .unwrap_or_else(|| SafeInt::from_big(self.into_raw().$method(&*other.big())))
+            }
+        }
+
+        impl $trait<SafeInt> for &SafeInt {
+            type Output = SafeInt;
+
+            #[inline(always)]
+            fn $method(self, other: SafeInt) -> SafeInt {
+                small_op!(self.as_small(), other.as_small(), $small)
+                    .unwrap_or_else(|| SafeInt::from_big((&*self.big()).$method(other.into_raw())))
+            }
+        }
+
+        impl $trait<&SafeInt> for &SafeInt {
+            type Output = SafeInt;
+
+            #[inline(always)]
+            fn $method(self, other: &SafeInt) -> SafeInt {
+                small_op!(self.as_small(), other.as_small(), $small)
+                    .unwrap_or_else(|| SafeInt::from_big((&*self.big()).$method(&*other.big())))
             }
         }
     };
@@ -801,11 +1646,14 @@ macro_rules! impl_pair_rem_ops {
 
             #[inline(always)]
             fn rem(self, other: SafeInt) -> Option<SafeInt> {
-                if other.0.is_zero() {
-                    None
-                } else {
-                    Some(SafeInt(self.0 % other.0))
+                if other.is_zero() {
+                    return None;
                 }
+                Some(
+                    small_op!(self.as_small(), other.as_small(), i128::checked_rem).unwrap_or_else(
+                        || SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).1),
+                    ),
+                )
             }
         }
 
@@ -814,11 +1662,14 @@ macro_rules! impl_pair_rem_ops {
 
             #[inline(always)]
             fn rem(self, other: &SafeInt) -> Option<SafeInt> {
-                if other.0.is_zero() {
-                    None
-                } else {
-                    Some(SafeInt(self.0 % &other.0))
+                if other.is_zero() {
+                    return None;
                 }
+                Some(
+                    small_op!(self.as_small(), other.as_small(), i128::checked_rem).unwrap_or_else(
+                        || SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).1),
+                    ),
+                )
             }
         }
 
@@ -827,11 +1678,14 @@ macro_rules! impl_pair_rem_ops {
 
             #[inline(always)]
             fn rem(self, other: SafeInt) -> Option<SafeInt> {
-                if other.0.is_zero() {
-                    None
-                } else {
-                    Some(SafeInt(self.0.clone() % other.0))
+                if other.is_zero() {
+                    return None;
                 }
+                Some(
+                    small_op!(self.as_small(), other.as_small(), i128::checked_rem).unwrap_or_else(
+                        || SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).1),
+                    ),
+                )
             }
         }
 
@@ -840,33 +1694,49 @@ macro_rules! impl_pair_rem_ops {
 
             #[inline(always)]
             fn rem(self, other: &SafeInt) -> Option<SafeInt> {
-                if other.0.is_zero() {
-                    None
-                } else {
-                    Some(SafeInt(self.0.clone() % &other.0))
+                if other.is_zero() {
+                    return None;
                 }
+                Some(
+                    small_op!(self.as_small(), other.as_small(), i128::checked_rem).unwrap_or_else(
+                        || SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).1),
+                    ),
+                )
             }
         }
     };
 }
 
-impl_pair_ops!(Add, add);
-impl_pair_ops!(Sub, sub);
-impl_pair_ops!(Mul, mul);
+impl_pair_ops!(Add, add, i128::checked_add);
+impl_pair_ops!(Sub, sub, i128::checked_sub);
+impl_pair_ops!(Mul, mul, i128::checked_mul, backend::mul);
 impl_pair_rem_ops!();
-impl_pair_ops!(BitAnd, bitand);
-impl_pair_ops!(BitOr, bitor);
-impl_pair_ops!(BitXor, bitxor);
+impl_pair_ops!(BitAnd, bitand, |a: i128, b: i128| Some(a & b));
+impl_pair_ops!(BitOr, bitor, |a: i128, b: i128| Some(a | b));
+impl_pair_ops!(BitXor, bitxor, |a: i128, b: i128| Some(a ^ b));
+
+/// Turns a primitive operand into what `BigInt` accepts for an operator: arithmetic has
+/// allocation-free scalar impls, while bitwise operators need a promoted `BigInt`.
+macro_rules! prim_operand {
+    (scalar, $value:expr) => {
+        $value
+    };
+    (promote, $value:expr) => {
+        BigInt::from($value)
+    };
+}
 
 macro_rules! impl_prim_ops {
-    ($trait:ident, $method:ident, [$($t:ty),*]) => {
+    ($trait:ident, $method:ident, $operand:ident, $small:expr, [$($t:ty),*]) => {
         $(
             impl $trait<$t> for SafeInt {
                 type Output = SafeInt;
 
                 #[inline(always)]
                 fn $method(self, other: $t) -> SafeInt {
-                    SafeInt(self.0.$method(BigInt::from(other)))
+                    small_op!(self.as_small(), i128::try_from(other).ok(), $small).unwrap_or_else(
+                        || SafeInt::from_big(self.into_raw().$method(prim_operand!($operand, other))),
+                    )
                 }
             }
 
@@ -875,7 +1745,9 @@ macro_rules! impl_prim_ops {
 
                 #[inline(always)]
                 fn $method(self, other: $t) -> SafeInt {
-                    SafeInt(self.0.clone().$method(BigInt::from(other)))
+                    small_op!(self.as_small(), i128::try_from(other).ok(), $small).unwrap_or_else(
+                        || SafeInt::from_big((&*self.big()).$method(prim_operand!($operand, other))),
+                    )
                 }
             }
 
@@ -884,7 +1756,9 @@ macro_rules! impl_prim_ops {
 
                 #[inline(always)]
                 fn $method(self, other: SafeInt) -> SafeInt {
-                    SafeInt(BigInt::from(self).$method(other.0))
+                    small_op!(i128::try_from(self).ok(), other.as_small(), $small).unwrap_or_else(
+                        || SafeInt::from_big(prim_operand!($operand, self).$method(other.into_raw())),
+                    )
                 }
             }
 
@@ -893,7 +1767,9 @@ macro_rules! impl_prim_ops {
 
                 #[inline(always)]
                 fn $method(self, other: &SafeInt) -> SafeInt {
-                    SafeInt(BigInt::from(self).$method(other.0.clone()))
+                    small_op!(i128::try_from(self).ok(), other.as_small(), $small).unwrap_or_else(
+                        || SafeInt::from_big(prim_operand!($operand, self).$method(&*other.big())),
+                    )
                 }
             }
         )*
@@ -909,10 +1785,12 @@ macro_rules! impl_prim_rem_ops {
                 #[inline(always)]
                 fn rem(self, other: $t) -> Option<SafeInt> {
                     if other == 0 {
-                        None
-                    } else {
-                        Some(SafeInt(self.0 % BigInt::from(other)))
+                        return None;
                     }
+                    Some(
+                        small_op!(self.as_small(), i128::try_from(other).ok(), i128::checked_rem)
+                            .unwrap_or_else(|| SafeInt::from_big(self.into_raw() % other)),
+                    )
                 }
             }
 
@@ -922,10 +1800,12 @@ macro_rules! impl_prim_rem_ops {
                 #[inline(always)]
                 fn rem(self, other: $t) -> Option<SafeInt> {
                     if other == 0 {
-                        None
-                    } else {
-                        Some(SafeInt(self.0.clone() % BigInt::from(other)))
+                        return None;
                     }
+                    Some(
+                        small_op!(self.as_small(), i128::try_from(other).ok(), i128::checked_rem)
+                            .unwrap_or_else(|| SafeInt::from_big(&*self.big() % other)),
+                    )
                 }
             }
 
@@ -934,11 +1814,13 @@ macro_rules! impl_prim_rem_ops {
 
                 #[inline(always)]
                 fn rem(self, other: SafeInt) -> Option<SafeInt> {
-                    if other.0.is_zero() {
-                        None
-                    } else {
-                        Some(SafeInt(BigInt::from(self) % other.0))
+                    if other.is_zero() {
+                        return None;
                     }
+                    Some(
+                        small_op!(i128::try_from(self).ok(), other.as_small(), i128::checked_rem)
+                            .unwrap_or_else(|| SafeInt::from_big(self % other.into_raw())),
+                    )
                 }
             }
 
@@ -947,11 +1829,13 @@ macro_rules! impl_prim_rem_ops {
 
                 #[inline(always)]
                 fn rem(self, other: &SafeInt) -> Option<SafeInt> {
-                    if other.0.is_zero() {
-                        None
-                    } else {
-                        Some(SafeInt(BigInt::from(self) % other.0.clone()))
+                    if other.is_zero() {
+                        return None;
                     }
+                    Some(
+                        small_op!(i128::try_from(self).ok(), other.as_small(), i128::checked_rem)
+                            .unwrap_or_else(|| SafeInt::from_big(self % &*other.big())),
+                    )
                 }
             }
         )*
@@ -961,6 +1845,8 @@ macro_rules! impl_prim_rem_ops {
 impl_prim_ops!(
     Add,
     add,
+    scalar,
+    i128::checked_add,
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
@@ -968,6 +1854,8 @@ impl_prim_ops!(
 impl_prim_ops!(
     Sub,
     sub,
+    scalar,
+    i128::checked_sub,
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
@@ -975,6 +1863,8 @@ impl_prim_ops!(
 impl_prim_ops!(
     Mul,
     mul,
+    scalar,
+    i128::checked_mul,
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
@@ -985,6 +1875,8 @@ impl_prim_rem_ops!(
 impl_prim_ops!(
     BitAnd,
     bitand,
+    promote,
+    |a: i128, b: i128| Some(a & b),
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
@@ -992,6 +1884,8 @@ impl_prim_ops!(
 impl_prim_ops!(
     BitOr,
     bitor,
+    promote,
+    |a: i128, b: i128| Some(a | b),
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
@@ -999,58 +1893,70 @@ impl_prim_ops!(
 impl_prim_ops!(
     BitXor,
     bitxor,
+    promote,
+    |a: i128, b: i128| Some(a ^ b),
     [
         u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
     ]
 );
 
-impl AddAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn add_assign(&mut self, rhs: SafeInt) {
-        self.0 += rhs.0;
-    }
-}
-
-impl AddAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn add_assign(&mut self, rhs: &SafeInt) {
-        self.0 += &rhs.0;
-    }
-}
-
-impl SubAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn sub_assign(&mut self, rhs: SafeInt) {
-        self.0 -= rhs.0;
-    }
-}
+macro_rules! impl_assign_ops {
+    ($trait:ident, $method:ident, $small:expr, $big:path) => {
+        impl $trait<SafeInt> for SafeInt {
+            #[inline(always)]
+            fn $method(&mut self, rhs: SafeInt) {
+                self.$method(&rhs);
+            }
+        }
 
-impl SubAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn sub_assign(&mut self, rhs: &SafeInt) {
-        self.0 -= &rhs.0;
-    }
-}
+        impl $trait<&SafeInt> for SafeInt {
+            #[inline(always)]
+            fn $method(&mut self, rhs: &SafeInt) {
+                match small_op!(self.as_small(), rhs.as_small(), $small) {
+                    Some(value) => *self = value,
+                    None => self.update_raw(|value| *value = $big(value, &rhs.big())),
+                }
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $op:tt, $small:expr) => {
+        impl $trait<SafeInt> for SafeInt {
+            #[inline(always)]
+            fn $method(&mut self, rhs: SafeInt) {
+                match small_op!(self.as_small(), rhs.as_small(), $small) {
+                    Some(value) => *self = value,
+                    None => self.update_raw(|value| *value $op rhs.into_raw()),
+                }
+            }
+        }
 
-impl MulAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn mul_assign(&mut self, rhs: SafeInt) {
-        self.0 *= rhs.0;
-    }
+        impl $trait<&SafeInt> for SafeInt {
+            #[inline(always)]
+            fn $method(&mut self, rhs: &SafeInt) {
+                match small_op!(self.as_small(), rhs.as_small(), $small) {
+                    Some(value) => *self = value,
+                    None => self.update_raw(|value| *value $op &*rhs.big()),
+                }
+            }
+        }
+    };
 }
 
-impl MulAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn mul_assign(&mut self, rhs: &SafeInt) {
-        self.0 *= &rhs.0;
-    }
-}
+impl_assign_ops!(AddAssign, add_assign, +=, i128::checked_add);
+impl_assign_ops!(SubAssign, sub_assign, -=, i128::checked_sub);
+impl_assign_ops!(MulAssign, mul_assign, i128::checked_mul, backend::mul);
+impl_assign_ops!(BitAndAssign, bitand_assign, &=, |a: i128, b: i128| Some(a & b));
+impl_assign_ops!(BitOrAssign, bitor_assign, |=, |a: i128, b: i128| Some(a | b));
+impl_assign_ops!(BitXorAssign, bitxor_assign, ^=, |a: i128, b: i128| Some(a ^ b));
 
 impl RemAssign<SafeInt> for SafeInt {
     #[inline(always)]
     fn rem_assign(&mut self, rhs: SafeInt) {
-        if !rhs.0.is_zero() {
-            self.0 %= rhs.0;
+        if !rhs.is_zero() {
+            match small_op!(self.as_small(), rhs.as_small(), i128::checked_rem) {
+                Some(value) => *self = value,
+                None => self.update_raw(|value| *value = backend::div_rem(value, &rhs.big()).1),
+            }
         }
     }
 }
@@ -1058,61 +1964,25 @@ impl RemAssign<SafeInt> for SafeInt {
 impl RemAssign<&SafeInt> for SafeInt {
     #[inline(always)]
     fn rem_assign(&mut self, rhs: &SafeInt) {
-        if !rhs.0.is_zero() {
-            self.0 %= &rhs.0;
+        if !rhs.is_zero() {
+            match small_op!(self.as_small(), rhs.as_small(), i128::checked_rem) {
+                Some(value) => *self = value,
+                None => self.update_raw(|value| *value = backend::div_rem(value, &rhs.big()).1),
+            }
         }
     }
 }
 
-impl BitAndAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitand_assign(&mut self, rhs: SafeInt) {
-        self.0 &= rhs.0;
-    }
-}
-
-impl BitAndAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitand_assign(&mut self, rhs: &SafeInt) {
-        self.0 &= &rhs.0;
-    }
-}
-
-impl BitOrAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitor_assign(&mut self, rhs: SafeInt) {
-        self.0 |= rhs.0;
-    }
-}
-
-impl BitOrAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitor_assign(&mut self, rhs: &SafeInt) {
-        self.0 |= &rhs.0;
-    }
-}
-
-impl BitXorAssign<SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitxor_assign(&mut self, rhs: SafeInt) {
-        self.0 ^= rhs.0;
-    }
-}
-
-impl BitXorAssign<&SafeInt> for SafeInt {
-    #[inline(always)]
-    fn bitxor_assign(&mut self, rhs: &SafeInt) {
-        self.0 ^= &rhs.0;
-    }
-}
-
 macro_rules! impl_assign_prim {
-    ($trait:ident, $method:ident, $op:tt, [$($t:ty),*]) => {
+    ($trait:ident, $method:ident, $op:tt, $operand:ident, $small:expr, [$($t:ty),*]) => {
         $(
             impl $trait<$t> for SafeInt {
                 #[inline(always)]
                 fn $method(&mut self, rhs: $t) {
-                    self.0 $op BigInt::from(rhs);
+                    match small_op!(self.as_small(), i128::try_from(rhs).ok(), $small) {
+                        Some(value) => *self = value,
+                        None => self.update_raw(|value| *value $op prim_operand!($operand, rhs)),
+                    }
                 }
             }
         )*
@@ -1126,7 +1996,10 @@ macro_rules! impl_rem_assign_prim {
                 #[inline(always)]
                 fn rem_assign(&mut self, rhs: $t) {
                     if rhs != 0 {
-                        self.0 %= BigInt::from(rhs);
+                        match small_op!(self.as_small(), i128::try_from(rhs).ok(), i128::checked_rem) {
+                            Some(value) => *self = value,
+                            None => self.update_raw(|value| *value %= rhs),
+                        }
                     }
                 }
             }
@@ -1134,26 +2007,29 @@ macro_rules! impl_rem_assign_prim {
     };
 }
 
-impl_assign_prim!(AddAssign, add_assign, +=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
-impl_assign_prim!(SubAssign, sub_assign, -=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
-impl_assign_prim!(MulAssign, mul_assign, *=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(AddAssign, add_assign, +=, scalar, i128::checked_add, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(SubAssign, sub_assign, -=, scalar, i128::checked_sub, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(MulAssign, mul_assign, *=, scalar, i128::checked_mul, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
 impl_rem_assign_prim!(
     u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 );
-impl_assign_prim!(BitAndAssign, bitand_assign, &=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
-impl_assign_prim!(BitOrAssign, bitor_assign, |=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
-impl_assign_prim!(BitXorAssign, bitxor_assign, ^=, [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(BitAndAssign, bitand_assign, &=, promote, |a: i128, b: i128| Some(a & b), [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(BitOrAssign, bitor_assign, |=, promote, |a: i128, b: i128| Some(a | b), [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
+impl_assign_prim!(BitXorAssign, bitxor_assign, ^=, promote, |a: i128, b: i128| Some(a ^ b), [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]);
 
 impl Div for SafeInt {
     type Output = Option<SafeInt>;
 
     #[inline(always)]
     fn div(self, other: SafeInt) -> Option<SafeInt> {
-        if other.0.is_zero() {
-            None
-        } else {
-            Some(SafeInt(self.0 / other.0))
+        if other.is_zero() {
+            return None;
         }
+        Some(
+            small_op!(self.as_small(), other.as_small(), i128::checked_div).unwrap_or_else(|| {
+                SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).0)
+            }),
+        )
     }
 }
 
@@ -1162,11 +2038,14 @@ impl Div<&SafeInt> for SafeInt {
 
     #[inline(always)]
     fn div(self, other: &SafeInt) -> Option<SafeInt> {
-        if other.0.is_zero() {
-            None
-        } else {
-            Some(SafeInt(self.0 / &other.0))
+        if other.is_zero() {
+            return None;
         }
+        Some(
+            small_op!(self.as_small(), other.as_small(), i128::checked_div).unwrap_or_else(|| {
+                SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).0)
+            }),
+        )
     }
 }
 
@@ -1175,11 +2054,14 @@ impl Div<SafeInt> for &SafeInt {
 
     #[inline(always)]
     fn div(self, other: SafeInt) -> Option<SafeInt> {
-        if other.0.is_zero() {
-            None
-        } else {
-            Some(SafeInt(self.0.clone() / other.0))
+        if other.is_zero() {
+            return None;
         }
+        Some(
+            small_op!(self.as_small(), other.as_small(), i128::checked_div).unwrap_or_else(|| {
+                SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).0)
+            }),
+        )
     }
 }
 
@@ -1188,11 +2070,14 @@ impl Div<&SafeInt> for &SafeInt {
 
     #[inline(always)]
     fn div(self, other: &SafeInt) -> Option<SafeInt> {
-        if other.0.is_zero() {
-            None
-        } else {
-            Some(SafeInt(self.0.clone() / &other.0))
+        if other.is_zero() {
+            return None;
         }
+        Some(
+            small_op!(self.as_small(), other.as_small(), i128::checked_div).unwrap_or_else(|| {
+                SafeInt::from_big(backend::div_rem(&self.big(), &other.big()).0)
+            }),
+        )
     }
 }
 
@@ -1205,10 +2090,12 @@ macro_rules! impl_div_safeint_rhs_prim {
                 #[inline(always)]
                 fn div(self, other: $t) -> Option<SafeInt> {
                     if other == 0 {
-                        None
-                    } else {
-                        Some(SafeInt(self.0 / BigInt::from(other)))
+                        return None;
                     }
+                    Some(
+                        small_op!(self.as_small(), i128::try_from(other).ok(), i128::checked_div)
+                            .unwrap_or_else(|| SafeInt::from_big(self.into_raw() / other)),
+                    )
                 }
             }
 
@@ -1218,10 +2105,12 @@ macro_rules! impl_div_safeint_rhs_prim {
                 #[inline(always)]
                 fn div(self, other: $t) -> Option<SafeInt> {
                     if other == 0 {
-                        None
-                    } else {
-                        Some(SafeInt(self.0.clone() / BigInt::from(other)))
+                        return None;
                     }
+                    Some(
+                        small_op!(self.as_small(), i128::try_from(other).ok(), i128::checked_div)
+                            .unwrap_or_else(|| SafeInt::from_big(&*self.big() / other)),
+                    )
                 }
             }
         )*
@@ -1236,11 +2125,13 @@ macro_rules! impl_div_prim_lhs_safeint {
 
                 #[inline(always)]
                 fn div(self, other: SafeInt) -> Option<SafeInt> {
-                    if other.0.is_zero() {
-                        None
-                    } else {
-                        Some(SafeInt(BigInt::from(self) / other.0))
+                    if other.is_zero() {
+                        return None;
                     }
+                    Some(
+                        small_op!(i128::try_from(self).ok(), other.as_small(), i128::checked_div)
+                            .unwrap_or_else(|| SafeInt::from_big(self / other.into_raw())),
+                    )
                 }
             }
 
@@ -1249,11 +2140,13 @@ macro_rules! impl_div_prim_lhs_safeint {
 
                 #[inline(always)]
                 fn div(self, other: &SafeInt) -> Option<SafeInt> {
-                    if other.0.is_zero() {
-                        None
-                    } else {
-                        Some(SafeInt(BigInt::from(self) / other.0.clone()))
+                    if other.is_zero() {
+                        return None;
                     }
+                    Some(
+                        small_op!(i128::try_from(self).ok(), other.as_small(), i128::checked_div)
+                            .unwrap_or_else(|| SafeInt::from_big(self / &*other.big())),
+                    )
                 }
             }
         )*
@@ -1265,51 +2158,70 @@ impl_div_safeint_rhs_prim!(
 );
 impl_div_prim_lhs_safeint!(
     u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
-);
-
-impl<T: Into<BigInt>> From<T> for SafeInt {
-    #[inline(always)]
-    fn from(value: T) -> SafeInt {
-        SafeInt(value.into())
-    }
-}
+);
 
-impl<T> PartialEq<T> for SafeInt
-where
-    T: Copy,
-    BigInt: From<T>,
-{
-    #[inline(always)]
-    fn eq(&self, other: &T) -> bool {
-        self.0 == BigInt::from(*other)
-    }
+macro_rules! impl_from_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl From<$prim> for SafeInt {
+                #[inline(always)]
+                fn from(value: $prim) -> SafeInt {
+                    SafeInt::small(value as i128)
+                }
+            }
+        )*
+    };
 }
 
-impl<T> PartialOrd<T> for SafeInt
-where
-    T: Copy,
-    BigInt: From<T>,
-{
+impl_from_primitive!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<u128> for SafeInt {
     #[inline(always)]
-    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
-        self.0.partial_cmp(&BigInt::from(*other))
+    fn from(value: u128) -> SafeInt {
+        match i128::try_from(value) {
+            Ok(small) => SafeInt::small(small),
+            Err(_) => SafeInt(Repr::Big(BigInt::from(value))),
+        }
     }
 }
 
 macro_rules! impl_prim_cmp {
     ($($t:ty),*) => {
         $(
+            impl PartialEq<$t> for SafeInt {
+                #[inline(always)]
+                fn eq(&self, other: &$t) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<$t> for SafeInt {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+
             impl PartialEq<SafeInt> for $t {
                 #[inline(always)]
                 fn eq(&self, other: &SafeInt) -> bool {
-                    BigInt::from(*self) == other.0
+                    match (i128::try_from(*self), other.as_small()) {
+                        (Ok(value), Some(other)) => value == other,
+                        _ => {
+                            let value = SafeInt::from(*self);
+                            value == *other
+                        }
+                    }
                 }
             }
 
             impl PartialOrd<SafeInt> for $t {
                 #[inline(always)]
                 fn partial_cmp(&self, other: &SafeInt) -> Option<Ordering> {
-                    BigInt::from(*self).partial_cmp(&other.0)
+                    match (i128::try_from(*self), other.as_small()) {
+                        (Ok(value), Some(other)) => Some(value.cmp(&other)),
+                        _ => Some(SafeInt::from(*self).cmp(other)),
+                    }
                 }
             }
         )*
@@ -1394,7 +2306,7 @@ impl<const N: usize> From<ConstSafeInt<N>> for SafeInt {
     fn from(value: ConstSafeInt<N>) -> SafeInt {
         let pos = value.0.first().cloned().unwrap_or(0) == 0;
         let magnitude = BigUint::from_bytes_be(&value.0[1..]);
-        let mut res = SafeInt(BigInt::from_biguint(Sign::Plus, magnitude));
+        let mut res = SafeInt::from_big(BigInt::from_biguint(Sign::Plus, magnitude));
         if !pos {
             res = -res;
         }
@@ -1407,7 +2319,7 @@ impl<const N: usize> From<&ConstSafeInt<N>> for SafeInt {
     fn from(value: &ConstSafeInt<N>) -> SafeInt {
         let pos = value.0.first().cloned().unwrap_or(0) == 0;
         let magnitude = BigUint::from_bytes_be(&value.0[1..]);
-        let mut res = SafeInt(BigInt::from_biguint(Sign::Plus, magnitude));
+        let mut res = SafeInt::from_big(BigInt::from_biguint(Sign::Plus, magnitude));
         if !pos {
             res = -res;
         }
@@ -1415,16 +2327,14 @@ impl<const N: usize> From<&ConstSafeInt<N>> for SafeInt {
     }
 }
 
-const LENCODE_SAFE_INT_VARIANT_MASK: u8 = 0x80;
-const LENCODE_SAFE_INT_VARIANT_BYTES: u8 = 0x80;
-const LENCODE_SAFE_INT_SIZE_MASK: u8 = 0x40;
-const LENCODE_SAFE_INT_SIZE_LARGE: u8 = 0x40;
-const LENCODE_SAFE_INT_PAYLOAD_MASK: u8 = 0x3F;
-const LENCODE_SAFE_INT_SMALL_MAX: u8 = LENCODE_SAFE_INT_PAYLOAD_MASK;
-const LENCODE_MAX_VARINT_BYTES: usize = LENCODE_SAFE_INT_PAYLOAD_MASK as usize;
+use crate::wire::{
+    LENCODE_MAX_VARINT_BYTES, LENCODE_SAFE_INT_DEDUPE_MIN_BYTES, LENCODE_SAFE_INT_PAYLOAD_MASK,
+    LENCODE_SAFE_INT_SIZE_LARGE, LENCODE_SAFE_INT_SIZE_MASK, LENCODE_SAFE_INT_SMALL_MAX,
+    LENCODE_SAFE_INT_VARIANT_BYTES, LENCODE_SAFE_INT_VARIANT_DEDUPE, LENCODE_SAFE_INT_VARIANT_MASK,
+};
 
 #[inline(always)]
-fn lencode_encode_biguint_varint_bytes(
+pub(crate) fn lencode_encode_biguint_varint_bytes(
     bytes: &[u8],
     writer: &mut impl Write,
 ) -> lencode::Result<usize> {
@@ -1448,6 +2358,7 @@ fn lencode_encode_biguint_varint_bytes(
 fn lencode_decode_biguint_varint_from_prefix(
     prefix: u8,
     reader: &mut impl Read,
+    strict: bool,
 ) -> lencode::Result<BigUint> {
     if (prefix & LENCODE_SAFE_INT_SIZE_MASK) == 0 {
         return Ok(BigUint::from(prefix & LENCODE_SAFE_INT_PAYLOAD_MASK));
@@ -1467,11 +2378,52 @@ fn lencode_decode_biguint_varint_from_prefix(
         }
         read += n;
     }
+    if strict {
+        // The most significant byte must be non-zero, and single-byte payloads must not fit
+        // the small form.
+        if buf[len - 1] == 0 || (len == 1 && buf[0] <= LENCODE_SAFE_INT_SMALL_MAX) {
+            return Err(Error::InvalidData);
+        }
+    }
     Ok(BigUint::from_bytes_le(&buf))
 }
 
+/// Reader adapter that records every byte pulled from the inner reader.
+struct LencodeRecordingReader<'a, R: Read> {
+    inner: &'a mut R,
+    recorded: Vec<u8>,
+}
+
+impl<R: Read> Read for LencodeRecordingReader<'_, R> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> lencode::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.recorded.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+#[inline(always)]
+fn lencode_decode_biguint_bytes_strict(reader: &mut impl Read) -> lencode::Result<BigUint> {
+    let mut recording = LencodeRecordingReader {
+        inner: reader,
+        recorded: Vec::new(),
+    };
+    let bytes: Vec<u8> = Vec::decode(&mut recording)?;
+    if bytes.len() <= LENCODE_MAX_VARINT_BYTES || bytes.last() == Some(&0) {
+        return Err(Error::InvalidData);
+    }
+    // Re-encoding pins down the length varint and the raw/compressed choice made by lencode.
+    let mut expected = Vec::with_capacity(recording.recorded.len());
+    bytes.encode(&mut expected)?;
+    if expected != recording.recorded {
+        return Err(Error::InvalidData);
+    }
+    Ok(BigUint::from_bytes_le(&bytes))
+}
+
 #[inline(always)]
-fn lencode_encode_biguint_with_variant(
+pub(crate) fn lencode_encode_biguint_with_variant(
     value: &BigUint,
     writer: &mut impl Write,
 ) -> lencode::Result<usize> {
@@ -1487,20 +2439,47 @@ fn lencode_encode_biguint_with_variant(
 }
 
 #[inline(always)]
-fn lencode_decode_biguint_with_variant(reader: &mut impl Read) -> lencode::Result<BigUint> {
+pub(crate) fn lencode_read_tag(reader: &mut impl Read) -> lencode::Result<u8> {
     let mut tag = [0u8; 1];
     if reader.read(&mut tag)? != 1 {
         return Err(Error::ReaderOutOfData);
     }
+    Ok(tag[0])
+}
 
-    if (tag[0] & LENCODE_SAFE_INT_VARIANT_MASK) != 0 {
+#[inline(always)]
+pub(crate) fn lencode_decode_biguint_from_tag(
+    tag: u8,
+    reader: &mut impl Read,
+    strict: bool,
+) -> lencode::Result<BigUint> {
+    if (tag & LENCODE_SAFE_INT_VARIANT_MASK) != 0 {
+        // Back-references are only meaningful to a `DedupeDecoder`.
+        if tag == LENCODE_SAFE_INT_VARIANT_DEDUPE {
+            return Err(Error::InvalidData);
+        }
+        if strict {
+            if tag != LENCODE_SAFE_INT_VARIANT_BYTES {
+                return Err(Error::InvalidData);
+            }
+            return lencode_decode_biguint_bytes_strict(reader);
+        }
         let bytes: Vec<u8> = Vec::decode(reader)?;
         Ok(BigUint::from_bytes_le(&bytes))
     } else {
-        lencode_decode_biguint_varint_from_prefix(tag[0], reader)
+        lencode_decode_biguint_varint_from_prefix(tag, reader, strict)
     }
 }
 
+#[inline(always)]
+pub(crate) fn lencode_decode_biguint_with_variant(
+    reader: &mut impl Read,
+    strict: bool,
+) -> lencode::Result<BigUint> {
+    let tag = lencode_read_tag(reader)?;
+    lencode_decode_biguint_from_tag(tag, reader, strict)
+}
+
 #[inline(always)]
 fn lencode_zigzag_encode_bigint(value: &BigInt) -> BigUint {
     if value.is_negative() {
@@ -1512,6 +2491,12 @@ fn lencode_zigzag_encode_bigint(value: &BigInt) -> BigUint {
     }
 }
 
+/// Zigzag-encodes an inline value; the result always fits the varint path.
+#[inline(always)]
+fn lencode_zigzag_encode_i128(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
 #[inline(always)]
 fn lencode_zigzag_decode_biguint(value: BigUint) -> BigInt {
     if value.is_odd() {
@@ -1522,15 +2507,57 @@ fn lencode_zigzag_decode_biguint(value: BigUint) -> BigInt {
     }
 }
 
+/// `Pack` writes the plain (non-deduplicated) encoding; it is what the dedupe tables store
+/// for the first occurrence of a large value.
+impl Pack for SafeInt {
+    #[inline(always)]
+    fn pack(&self, writer: &mut impl Write) -> lencode::Result<usize> {
+        match &self.0 {
+            Repr::Small(value) => {
+                let encoded = lencode_zigzag_encode_i128(*value);
+                let len = (u128::BITS - encoded.leading_zeros()).div_ceil(8) as usize;
+                lencode_encode_biguint_varint_bytes(&encoded.to_le_bytes()[..len], writer)
+            }
+            Repr::Big(value) => {
+                let encoded = lencode_zigzag_encode_bigint(value);
+                lencode_encode_biguint_with_variant(&encoded, writer)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(reader: &mut impl Read) -> lencode::Result<Self> {
+        let unsigned = lencode_decode_biguint_with_variant(reader, false)?;
+        Ok(SafeInt::from_big(lencode_zigzag_decode_biguint(unsigned)))
+    }
+}
+
 impl Encode for SafeInt {
     #[inline(always)]
     fn encode_ext(
         &self,
         writer: &mut impl Write,
-        _dedupe_encoder: Option<&mut DedupeEncoder>,
+        dedupe_encoder: Option<&mut DedupeEncoder>,
     ) -> lencode::Result<usize> {
-        let encoded = lencode_zigzag_encode_bigint(&self.0);
-        lencode_encode_biguint_with_variant(&encoded, writer)
+        let large = match &self.0 {
+            Repr::Small(value) => {
+                let bits = u128::BITS - lencode_zigzag_encode_i128(*value).leading_zeros();
+                u64::from(bits) > 8 * LENCODE_SAFE_INT_DEDUPE_MIN_BYTES
+            }
+            // Values outside the `i128` range always have a zigzag payload over 16 bytes.
+            Repr::Big(value) => value.to_i128().is_none_or(|value| {
+                let bits = u128::BITS - lencode_zigzag_encode_i128(value).leading_zeros();
+                u64::from(bits) > 8 * LENCODE_SAFE_INT_DEDUPE_MIN_BYTES
+            }),
+        };
+        if let Some(encoder) = dedupe_encoder
+            && large
+        {
+            let mut total = writer.write(&[LENCODE_SAFE_INT_VARIANT_DEDUPE])?;
+            total += encoder.encode(self, writer)?;
+            return Ok(total);
+        }
+        self.pack(writer)
     }
 }
 
@@ -1538,10 +2565,44 @@ impl Decode for SafeInt {
     #[inline(always)]
     fn decode_ext(
         reader: &mut impl Read,
-        _dedupe_decoder: Option<&mut DedupeDecoder>,
+        dedupe_decoder: Option<&mut DedupeDecoder>,
     ) -> lencode::Result<Self> {
-        let unsigned = lencode_decode_biguint_with_variant(reader)?;
-        Ok(SafeInt::from_raw(lencode_zigzag_decode_biguint(unsigned)))
+        let tag = lencode_read_tag(reader)?;
+        if tag == LENCODE_SAFE_INT_VARIANT_DEDUPE
+            && let Some(decoder) = dedupe_decoder
+        {
+            return decoder.decode(reader);
+        }
+        let unsigned = lencode_decode_biguint_from_tag(tag, reader, false)?;
+        Ok(SafeInt::from_big(lencode_zigzag_decode_biguint(unsigned)))
+    }
+}
+
+impl SafeInt {
+    /// Decodes a `SafeInt`, rejecting any encoding that is not the canonical (minimal) one.
+    ///
+    /// [`Decode`] accepts every well-formed encoding of a value, but several byte strings can
+    /// decode to the same integer. This strict variant accepts exactly the bytes that
+    /// [`Encode`] produces and fails with [`Error::InvalidData`] otherwise:
+    ///
+    /// - zigzag values in `[0, 63]` must use the one-byte small form;
+    /// - the varint large form must not carry trailing (most significant) zero bytes;
+    /// - the bytes path is only valid for payloads longer than 63 bytes, its header must be
+    ///   exactly `0x80`, and the payload must have no trailing zero bytes and match lencode's
+    ///   own `Vec<u8>` encoding (length varint and compression choice).
+    ///
+    /// # Examples
+    /// ```
+    /// use lencode::io::Cursor;
+    /// use safe_bigmath::SafeInt;
+    ///
+    /// assert_eq!(SafeInt::decode_strict(&mut Cursor::new(&[0x02][..])).unwrap(), 1);
+    /// // `1` written with the large header is well-formed but not canonical.
+    /// assert!(SafeInt::decode_strict(&mut Cursor::new(&[0x41, 0x02][..])).is_err());
+    /// ```
+    pub fn decode_strict(reader: &mut impl Read) -> lencode::Result<SafeInt> {
+        let unsigned = lencode_decode_biguint_with_variant(reader, true)?;
+        Ok(SafeInt::from_big(lencode_zigzag_decode_biguint(unsigned)))
     }
 }
 
@@ -1845,8 +2906,7 @@ fn pow_ratio_scaled_handles_extreme_delta_x() {
     let result = SafeInt::pow_ratio_scaled(&x, &base_den, &w1, &w2, precision, &scale)
         .expect("extreme delta x");
 
-    let expected = ((x.0.to_f64().unwrap() / base_den.0.to_f64().unwrap())
-        .powf(w1.0.to_f64().unwrap() / w2.0.to_f64().unwrap())
+    let expected = ((x.to_f64() / base_den.to_f64()).powf(w1.to_f64() / w2.to_f64())
         * 1_000_000_000_000_000_000f64)
         .floor() as u128;
     let delta = (result.clone() - SafeInt::from(expected)).abs();
@@ -1917,6 +2977,469 @@ fn pow_bigint_base_with_crafted_gcd_values() {
     );
 }
 
+#[test]
+fn test_div_round() {
+    use RoundingMode::*;
+
+    // (numerator, denominator, [TowardZero, AwayFromZero, Floor, Ceil, HalfUp, HalfDown, HalfEven])
+    let cases: &[(i32, i32, [i32; 7])] = &[
+        (7, 2, [3, 4, 3, 4, 4, 3, 4]),
+        (5, 2, [2, 3, 2, 3, 3, 2, 2]),
+        (-5, 2, [-2, -3, -3, -2, -3, -2, -2]),
+        (5, -2, [-2, -3, -3, -2, -3, -2, -2]),
+        (-7, -2, [3, 4, 3, 4, 4, 3, 4]),
+        (7, 3, [2, 3, 2, 3, 2, 2, 2]),
+        (-8, 3, [-2, -3, -3, -2, -3, -3, -3]),
+        (6, 3, [2, 2, 2, 2, 2, 2, 2]),
+        (0, 5, [0, 0, 0, 0, 0, 0, 0]),
+    ];
+    let modes = [
+        TowardZero,
+        AwayFromZero,
+        Floor,
+        Ceil,
+        HalfUp,
+        HalfDown,
+        HalfEven,
+    ];
+
+    for &(num, den, expected) in cases {
+        for (mode, want) in modes.iter().zip(expected) {
+            assert_eq!(
+                SafeInt::from(num).div_round(&SafeInt::from(den), *mode),
+                Some(SafeInt::from(want)),
+                "{num} / {den} with {mode:?}"
+            );
+        }
+    }
+    assert_eq!(SafeInt::from(1).div_round(&SafeInt::zero(), HalfEven), None);
+}
+
+#[test]
+fn test_bytes_variable_width() {
+    let value = SafeInt::from(0x0102_0304u32);
+    assert_eq!(value.to_bytes_be(), Some(vec![1, 2, 3, 4]));
+    assert_eq!(value.to_bytes_le(), Some(vec![4, 3, 2, 1]));
+    assert_eq!(SafeInt::from_bytes_be(&[1, 2, 3, 4]), value);
+    assert_eq!(SafeInt::from_bytes_le(&[4, 3, 2, 1]), value);
+    assert_eq!(SafeInt::zero().to_bytes_be(), Some(vec![0]));
+    assert_eq!(SafeInt::from(-1).to_bytes_le(), None);
+    assert_eq!(SafeInt::from(-1).to_bytes_be(), None);
+
+    assert_eq!(SafeInt::from(-1).to_signed_bytes_be(), vec![0xFF]);
+    assert_eq!(SafeInt::from(128).to_signed_bytes_be(), vec![0x00, 0x80]);
+    assert_eq!(SafeInt::from(-129).to_signed_bytes_le(), vec![0x7F, 0xFF]);
+    assert_eq!(SafeInt::from_signed_bytes_be(&[0xFF, 0x7F]), -129);
+    assert_eq!(SafeInt::from_signed_bytes_le(&[0x80, 0x00]), 128);
+    assert_eq!(SafeInt::from_bytes_be(&[]), 0);
+    assert_eq!(SafeInt::from_signed_bytes_be(&[]), 0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_bytes_fixed_width() {
+    let u256_max = SafeInt::from_raw(BigInt::from(1u8) << 256usize) - 1u8;
+    let word = u256_max.to_be_bytes_fixed::<32>().unwrap();
+    assert_eq!(word, [0xFF; 32]);
+    assert_eq!(SafeInt::from_bytes_be(&word), u256_max);
+    assert_eq!((u256_max.clone() + 1u8).to_be_bytes_fixed::<32>(), None);
+    assert_eq!(u256_max.to_le_bytes_fixed::<33>().unwrap()[32], 0);
+
+    let i256_min = -SafeInt::from_raw(BigInt::from(1u8) << 255usize);
+    let i256_max = SafeInt::from_raw(BigInt::from(1u8) << 255usize) - 1u8;
+    let word = i256_min.to_signed_be_bytes_fixed::<32>().unwrap();
+    assert_eq!(word[0], 0x80);
+    assert!(word[1..].iter().all(|&b| b == 0));
+    assert_eq!(SafeInt::from_signed_bytes_be(&word), i256_min);
+    assert_eq!(
+        SafeInt::from_signed_bytes_le(&i256_max.to_signed_le_bytes_fixed::<32>().unwrap()),
+        i256_max
+    );
+    assert_eq!((i256_min - 1u8).to_signed_be_bytes_fixed::<32>(), None);
+    assert_eq!((i256_max + 1u8).to_signed_le_bytes_fixed::<32>(), None);
+
+    // Solana-style u128 little-endian field
+    let value = SafeInt::from(u128::MAX - 5);
+    assert_eq!(
+        value.to_le_bytes_fixed::<16>(),
+        Some((u128::MAX - 5).to_le_bytes())
+    );
+    assert_eq!(
+        SafeInt::from(-5i128).to_signed_le_bytes_fixed::<16>(),
+        Some((-5i128).to_le_bytes())
+    );
+    assert_eq!(
+        SafeInt::from(u128::MAX).to_signed_le_bytes_fixed::<16>(),
+        None
+    );
+
+    assert_eq!(SafeInt::zero().to_be_bytes_fixed::<0>(), Some([]));
+    assert_eq!(SafeInt::one().to_be_bytes_fixed::<0>(), None);
+    assert_eq!(SafeInt::from(-7).to_le_bytes_fixed::<8>(), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_f64_conversions() {
+    assert_eq!(SafeInt::zero().to_f64(), 0.0);
+    assert_eq!(
+        SafeInt::from(u64::MAX).to_f64(),
+        18_446_744_073_709_551_616.0
+    );
+    // 2^53 + 1 is a tie between 2^53 and 2^53 + 2, broken towards the even mantissa.
+    assert_eq!(
+        SafeInt::from((1u64 << 53) + 1).to_f64(),
+        9_007_199_254_740_992.0
+    );
+    assert_eq!(
+        SafeInt::from((1u64 << 53) + 3).to_f64(),
+        9_007_199_254_740_996.0
+    );
+    // Bits far below the mantissa still break the tie upwards.
+    let sticky = SafeInt::from_raw((BigInt::from((1u64 << 53) + 1) << 200usize) + 1u8);
+    assert_eq!(sticky.to_f64(), 9_007_199_254_740_994.0 * 2f64.powi(200));
+    assert_eq!(SafeInt::from(-7).to_f64(), -7.0);
+    assert_eq!(
+        SafeInt::from_raw(BigInt::from(1u8) << 1024usize).to_f64(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        SafeInt::from_raw(-(BigInt::from(1u8) << 1024usize)).to_f64(),
+        f64::NEG_INFINITY
+    );
+
+    assert_eq!(SafeInt::from_f64_exact(0.0).unwrap(), 0);
+    assert_eq!(SafeInt::from_f64_exact(-0.0).unwrap(), 0);
+    assert_eq!(SafeInt::from_f64_exact(-12.0).unwrap(), -12);
+    assert_eq!(
+        SafeInt::from_f64_exact(4_503_599_627_370_495.0).unwrap(),
+        4_503_599_627_370_495u64
+    );
+    assert_eq!(
+        SafeInt::from_f64_exact(f64::MAX).unwrap(),
+        SafeInt::from_raw(((BigInt::from(1u8) << 53usize) - 1u8) << 971usize)
+    );
+    assert_eq!(SafeInt::from_f64_exact(0.5), None);
+    assert_eq!(SafeInt::from_f64_exact(f64::MIN_POSITIVE), None);
+    assert_eq!(SafeInt::from_f64_exact(4_503_599_627_370_495.5), None);
+    assert_eq!(SafeInt::from_f64_exact(f64::INFINITY), None);
+    for value in [1.0, -1.0, 3.0e15, -9.0e18, 1.0e300] {
+        assert_eq!(SafeInt::from_f64_exact(value).unwrap().to_f64(), value);
+    }
+}
+
+#[test]
+fn test_primitive_ops_match_promoted() {
+    let big = SafeInt::from(-7) * SafeInt::from(u128::MAX);
+    for value in [SafeInt::from(-1234), SafeInt::zero(), big] {
+        for prim in [-300i64, -1, 0, 7, i64::MAX] {
+            let other = SafeInt::from(prim);
+            assert_eq!(&value + prim, &value + &other);
+            assert_eq!(&value - prim, &value - &other);
+            assert_eq!(prim - &value, &other - &value);
+            assert_eq!(&value * prim, &value * &other);
+            assert_eq!(&value / prim, &value / &other);
+            assert_eq!(prim / &value, &other / &value);
+            assert_eq!(&value % prim, &value % &other);
+            assert_eq!(prim % &value, &other % &value);
+            assert_eq!(&value & prim, &value & &other);
+
+            let mut assigned = value.clone();
+            assigned += prim;
+            assigned *= prim;
+            assigned -= prim;
+            assigned %= prim;
+            let mut expected = value.clone();
+            expected += &other;
+            expected *= &other;
+            expected -= &other;
+            expected %= &other;
+            assert_eq!(assigned, expected);
+        }
+    }
+}
+
+#[test]
+fn test_u64_digits_roundtrip() {
+    let values = [
+        SafeInt::zero(),
+        SafeInt::from(-1),
+        SafeInt::from(i128::MIN),
+        SafeInt::from(i128::MAX),
+        SafeInt::from(u128::MAX),
+        -SafeInt::from(u128::MAX),
+        SafeInt::from(3).pow(200),
+        -SafeInt::from(3).pow(200),
+    ];
+    for value in values {
+        let (negative, digits) = value.to_u64_digits();
+        assert_eq!(negative, value.is_negative());
+        assert_eq!(digits, value.big().magnitude().to_u64_digits());
+        assert_eq!(SafeInt::from_u64_digits(negative, &digits), value);
+    }
+    let mut padded = SafeInt::from(i128::MIN).to_u64_digits().1;
+    padded.extend([0, 0]);
+    assert_eq!(
+        SafeInt::from_u64_digits(true, &padded).as_small(),
+        Some(i128::MIN)
+    );
+    assert!(
+        SafeInt::from_u64_digits(false, &[0, 0, 0, 1])
+            .as_small()
+            .is_none()
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_inline_repr_boundaries() {
+    let max = SafeInt::from(i128::MAX);
+    let min = SafeInt::from(i128::MIN);
+    let above = &max + 1u8;
+    let below = &min - 1u8;
+    assert!(max.as_small().is_some() && min.as_small().is_some());
+    assert!(above.as_small().is_none() && below.as_small().is_none());
+    assert_eq!(*above.raw(), BigInt::from(i128::MAX) + 1u8);
+    assert_eq!(below, SafeInt::from_raw(BigInt::from(i128::MIN) - 1u8));
+
+    // Results that shrink back into range are stored inline again.
+    assert_eq!((&above - 1u8).as_small(), Some(i128::MAX));
+    let mut value = above.clone();
+    value -= &above;
+    assert_eq!(value.as_small(), Some(0));
+    assert!(value.is_zero());
+    assert_eq!(SafeInt::from_big(BigInt::from(-5)).as_small(), Some(-5));
+
+    // `from_raw` normalises in-range values to inline storage.
+    assert_eq!(SafeInt::from_raw(BigInt::from(-5)).as_small(), Some(-5));
+    assert!(SafeInt::from_raw(BigInt::zero()).is_zero());
+    assert_eq!(SafeInt::from_raw(BigInt::from(i128::MAX) + 1u8), above);
+
+    assert_eq!(-&min, above);
+    assert_eq!((-min.clone()).abs(), above);
+    assert_eq!(min.clone().abs(), above);
+    assert_eq!((&min / -1i32).unwrap(), above);
+    assert_eq!((&min % SafeInt::neg_one()).unwrap(), 0);
+    assert_eq!(&min * -1i32, above);
+    assert_eq!(SafeInt::from(2).pow(127), above);
+    assert_eq!(
+        min.clone().div_rem(SafeInt::from(-1)).unwrap(),
+        (above.clone(), SafeInt::zero())
+    );
+    assert_eq!(
+        min.div_round(&SafeInt::from(-2), RoundingMode::HalfEven)
+            .unwrap(),
+        SafeInt::from(1u128 << 126)
+    );
+
+    let mut ordered = [above.clone(), max.clone(), below.clone(), min.clone()];
+    ordered.sort();
+    assert_eq!(
+        ordered,
+        [below.clone(), min.clone(), max.clone(), above.clone()]
+    );
+    assert!(u128::MAX > max && u128::MAX < &above * 2u8);
+    assert_eq!(u128::MAX, &above * 2u8 - 1u8);
+    assert_eq!(
+        format!("{above:?}"),
+        "SafeInt(170141183460469231731687303715884105728)"
+    );
+    assert_eq!(format!("{:x}", SafeInt::from(-255)), "-ff");
+
+    // The inline encoder writes exactly what the `BigInt` path would.
+    for value in [min, below, max, above, SafeInt::from(-33), SafeInt::zero()] {
+        let mut inline = Vec::new();
+        value.encode(&mut inline).unwrap();
+        let mut expected = Vec::new();
+        let zigzag = lencode_zigzag_encode_bigint(&value.raw());
+        lencode_encode_biguint_with_variant(&zigzag, &mut expected).unwrap();
+        assert_eq!(inline, expected);
+        assert_eq!(
+            SafeInt::decode(&mut Cursor::new(&inline[..])).unwrap(),
+            value
+        );
+    }
+}
+
+#[test]
+fn test_primitive_conversions() {
+    let big = SafeInt::from(u128::MAX) * 4u8 + 3u8;
+    let values = [
+        SafeInt::from(-129),
+        SafeInt::from(-1),
+        SafeInt::zero(),
+        SafeInt::from(255),
+        SafeInt::from(300),
+        SafeInt::from(i64::MIN),
+        big.clone(),
+        -big,
+    ];
+    for value in &values {
+        let wide = value.to_i128().map(|v| v as u128).unwrap_or_else(|| {
+            let low = value.to_signed_bytes_le();
+            let mut bytes = [if value.is_negative() { 0xff } else { 0 }; 16];
+            bytes.copy_from_slice(&low[..16]);
+            u128::from_le_bytes(bytes)
+        });
+        assert_eq!(value.to_u8_wrapping(), wide as u8, "{value}");
+        assert_eq!(value.to_i16_wrapping(), wide as i16, "{value}");
+        assert_eq!(value.to_u64_wrapping(), wide as u64, "{value}");
+        assert_eq!(value.to_i128_wrapping(), wide as i128, "{value}");
+        assert_eq!(value.to_u128_wrapping(), wide, "{value}");
+
+        assert_eq!(u8::try_from(value).ok(), value.to_u8());
+        assert_eq!(i64::try_from(value.clone()).ok(), value.to_i64());
+    }
+
+    assert_eq!(SafeInt::from(-129).to_i8_saturating(), i8::MIN);
+    assert_eq!(SafeInt::from(300).to_u8_saturating(), u8::MAX);
+    assert_eq!(SafeInt::from(-5).to_u32_saturating(), 0);
+    assert_eq!(SafeInt::from(42).to_isize_saturating(), 42);
+    assert_eq!(SafeInt::from(u128::MAX).to_i128_saturating(), i128::MAX);
+    assert_eq!(SafeInt::from(-1).to_u16_wrapping(), u16::MAX);
+    assert_eq!(SafeInt::from(256 + 7).to_u8_wrapping(), 7);
+
+    assert_eq!(
+        u8::try_from(SafeInt::from(256)),
+        Err(ConversionError::Overflow { target: "u8" })
+    );
+    assert_eq!(
+        u64::try_from(&SafeInt::from(-1)),
+        Err(ConversionError::Underflow { target: "u64" })
+    );
+    assert_eq!(
+        format!("{}", i8::try_from(SafeInt::from(-200)).unwrap_err()),
+        "value is less than the minimum i8"
+    );
+    let generic: Result<u64, _> = SafeInt::from(9).try_into();
+    assert_eq!(generic, Ok(9));
+}
+
+#[test]
+fn test_display_flags() {
+    for n in [0i64, 42, -42, 1_234_567_890] {
+        let value = SafeInt::from(n);
+        assert_eq!(format!("{value:>12}"), format!("{n:>12}"));
+        assert_eq!(format!("{value:<12}|"), format!("{n:<12}|"));
+        assert_eq!(format!("{value:_^12}"), format!("{n:_^12}"));
+        assert_eq!(format!("{value:+}"), format!("{n:+}"));
+        assert_eq!(format!("{value:08}"), format!("{n:08}"));
+        assert_eq!(format!("{value:+08}"), format!("{n:+08}"));
+        assert_eq!(format!("{value:.3}"), format!("{n:.3}"));
+    }
+    for n in [i128::MIN, i128::MAX] {
+        assert_eq!(format!("{:+}", SafeInt::from(n)), format!("{n:+}"));
+    }
+}
+
+#[test]
+fn test_pow10() {
+    for exp in [0, 1, 18, 38, 39, 127, 128, 300] {
+        assert_eq!(*pow10(exp), SafeInt::from(10).pow(exp as u32));
+    }
+    assert!(matches!(pow10(6), Cow::Borrowed(_)));
+    assert!(core::ptr::eq(&*pow10(100), &*pow10(100)));
+    assert!(matches!(pow10_unsigned(100), Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_scientific_notation() {
+    assert_eq!(
+        "3E+18".parse::<SafeInt>().unwrap(),
+        3_000_000_000_000_000_000u64
+    );
+    assert_eq!("-2.5e3".parse::<SafeInt>().unwrap(), -2_500);
+    assert_eq!("1200e-2".parse::<SafeInt>().unwrap(), 12);
+    assert_eq!("0e-99".parse::<SafeInt>().unwrap(), 0);
+    assert_eq!(
+        "25e-1".parse::<SafeInt>(),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 0,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        "1.5".parse::<SafeInt>(),
+        Err(ParseError::TrailingCharacters { index: 1 })
+    );
+    assert_eq!(
+        "1e99999".parse::<SafeInt>(),
+        Err(ParseError::ExponentOutOfRange { index: 2 })
+    );
+    "0x1e3"
+        .parse::<SafeInt>()
+        .map(|v| assert_eq!(v, 0x1e3))
+        .unwrap();
+
+    let value = SafeInt::from(123_456_789);
+    assert_eq!(format!("{value:e}"), "1.23456789e8");
+    assert_eq!(format!("{value:E}"), "1.23456789E8");
+    assert_eq!(format!("{:.2e}", value), "1.23e8");
+    assert_eq!(format!("{:.0e}", value), "1e8");
+    assert_eq!(format!("{:.10e}", value), "1.2345678900e8");
+    assert_eq!(format!("{:.2e}", SafeInt::from(-99_960)), "-1.00e5");
+    assert_eq!(format!("{:.1e}", SafeInt::from(125)), "1.2e2");
+    assert_eq!(format!("{:.1e}", SafeInt::from(135)), "1.4e2");
+    assert_eq!(format!("{:e}", SafeInt::from(1_000)), "1e3");
+    assert_eq!(format!("{:e}", SafeInt::zero()), "0e0");
+    assert_eq!(format!("{:.2e}", SafeInt::zero()), "0.00e0");
+    assert_eq!(format!("{:+e}", SafeInt::from(7)), "+7e0");
+    assert_eq!(format!("{:>8e}", SafeInt::from(-42)), "  -4.2e1");
+    for n in [1i64, -7, 10, 123_456_789, -1_000_000_007] {
+        assert_eq!(format!("{:e}", SafeInt::from(n)), format!("{:e}", n));
+        assert_eq!(format!("{:.3E}", SafeInt::from(n)), format!("{:.3E}", n));
+        let text = format!("{:e}", SafeInt::from(n));
+        assert_eq!(text.parse::<SafeInt>().unwrap(), n);
+    }
+}
+
+#[test]
+fn test_radix_round_trip() {
+    let values = [
+        SafeInt::zero(),
+        SafeInt::from(1),
+        SafeInt::from(-1),
+        SafeInt::from(u64::MAX),
+        SafeInt::from(i128::MIN),
+        SafeInt::from_str("-3798473984798349793847979798879878978334738744739847983749837")
+            .unwrap(),
+    ];
+    for value in values {
+        for radix in 2..=36 {
+            let text = value.to_str_radix(radix).unwrap();
+            assert_eq!(SafeInt::from_str_radix(&text, radix).unwrap(), value);
+            assert_eq!(
+                SafeInt::from_str_radix(&text.to_uppercase(), radix).unwrap(),
+                value
+            );
+        }
+    }
+    assert_eq!(SafeInt::from(10).to_str_radix(0), None);
+    assert_eq!(SafeInt::from(10).to_str_radix(1), None);
+    SafeInt::from_str_radix("10", 37).unwrap_err();
+    SafeInt::from_str_radix("", 10).unwrap_err();
+    SafeInt::from_str_radix("-", 10).unwrap_err();
+    SafeInt::from_str_radix("0x10", 16).unwrap_err();
+    SafeInt::from_str_radix("12", 2).unwrap_err();
+}
+
+#[test]
+fn test_radix_formatting_traits() {
+    let value = SafeInt::from(255);
+    assert_eq!(format!("{value:x}"), "ff");
+    assert_eq!(format!("{value:X}"), "FF");
+    assert_eq!(format!("{value:#x}"), "0xff");
+    assert_eq!(format!("{value:o}"), "377");
+    assert_eq!(format!("{value:b}"), "11111111");
+    assert_eq!(format!("{value:#010b}"), "0b11111111");
+    assert_eq!(format!("{:x}", SafeInt::from(-255)), "-ff");
+    assert_eq!(format!("{:#X}", SafeInt::from(-255)), "-0xFF");
+    assert_eq!(
+        SafeInt::from_str(&format!("{:#x}", SafeInt::from(u128::MAX))).unwrap(),
+        u128::MAX
+    );
+}
+
 #[test]
 fn test_zero() {
     assert_eq!(SafeInt::zero(), 0);
@@ -1944,6 +3467,7 @@ fn expected_varint_bytes(value: &BigUint) -> Vec<u8> {
 }
 
 #[test]
+#[allow(deprecated)]
 fn lencode_safe_int_roundtrip() {
     let big = BigInt::from(1u8) << 200usize;
     let values = [
@@ -1991,6 +3515,7 @@ fn lencode_safe_int_known_encodings() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn lencode_safe_int_large_encoding_structure() {
     let base = BigInt::from(1u8) << 200usize;
     let values = [
@@ -2005,7 +3530,7 @@ fn lencode_safe_int_large_encoding_structure() {
 
         let raw = value.raw();
         let zigzag = if raw.is_negative() {
-            let magnitude = (-raw).to_biguint().expect("negative magnitude");
+            let magnitude = (-&*raw).to_biguint().expect("negative magnitude");
             (magnitude << 1usize) - BigUint::from(1u8)
         } else {
             let magnitude = raw.to_biguint().unwrap_or(BigUint::ZERO);
@@ -2019,6 +3544,7 @@ fn lencode_safe_int_large_encoding_structure() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn lencode_safe_int_matches_varint_bytes() {
     let values = [
         SafeInt::from(0),
@@ -2031,7 +3557,7 @@ fn lencode_safe_int_matches_varint_bytes() {
     for value in values {
         let raw = value.raw();
         let zigzag = if raw.is_negative() {
-            let magnitude = (-raw).to_biguint().expect("negative magnitude");
+            let magnitude = (-&*raw).to_biguint().expect("negative magnitude");
             (magnitude << 1usize) - BigUint::from(1u8)
         } else {
             let magnitude = raw.to_biguint().unwrap_or(BigUint::ZERO);
@@ -2060,6 +3586,7 @@ fn lencode_safe_int_rejects_truncated_payload() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn lencode_safe_int_large_values_use_bytes_variant() {
     let too_large = BigInt::from(1u8) << (8 * LENCODE_MAX_VARINT_BYTES);
     let value = SafeInt::from_raw(too_large);
@@ -2068,13 +3595,141 @@ fn lencode_safe_int_large_values_use_bytes_variant() {
 
     assert_eq!(buf[0], LENCODE_SAFE_INT_VARIANT_BYTES);
     let bytes: Vec<u8> = Vec::decode(&mut Cursor::new(&buf[1..])).unwrap();
-    let zigzag = lencode_zigzag_encode_bigint(value.raw());
+    let zigzag = lencode_zigzag_encode_bigint(&value.raw());
     assert_eq!(bytes, zigzag.to_bytes_le());
 
     let decoded = SafeInt::decode(&mut Cursor::new(&buf)).unwrap();
     assert_eq!(decoded, value);
 }
 
+#[test]
+#[allow(deprecated)]
+fn lencode_safe_int_decode_strict_accepts_canonical() {
+    let big = BigInt::from(1u8) << 200usize;
+    let huge = BigInt::from(1u8) << (8 * LENCODE_MAX_VARINT_BYTES);
+    let values = [
+        SafeInt::from(0),
+        SafeInt::from(31),
+        SafeInt::from(-32),
+        SafeInt::from(32),
+        SafeInt::from(300),
+        SafeInt::from(-1_000_000),
+        SafeInt::from_raw(big.clone()),
+        SafeInt::from_raw(-big),
+        SafeInt::from_raw(huge.clone()),
+        SafeInt::from_raw(-huge),
+    ];
+
+    for value in values {
+        let mut buf = Vec::new();
+        value.encode(&mut buf).unwrap();
+        let mut cursor = Cursor::new(&buf);
+        let decoded = SafeInt::decode_strict(&mut cursor).unwrap();
+        assert_eq!(decoded, value);
+    }
+}
+
+#[test]
+fn lencode_safe_int_decode_strict_rejects_non_canonical() {
+    let cases: &[&[u8]] = &[
+        // small value written with the large header
+        &[0x41, 0x02],
+        &[0x41, 0x3F],
+        // trailing zero byte in the varint payload
+        &[0x42, 0x80, 0x00],
+        &[0x43, 0x58, 0x02, 0x00],
+        // bytes path carrying a value that fits the varint path
+        &[0x80, 0x02, 0x02],
+        // bytes path header with stray bits set
+        &[0x81, 0x02, 0x02],
+    ];
+
+    for &data in cases {
+        let err = SafeInt::decode_strict(&mut Cursor::new(data)).unwrap_err();
+        assert!(matches!(err, Error::InvalidData), "{data:?}");
+        // The lenient decoder still accepts these forms.
+        SafeInt::decode(&mut Cursor::new(data)).unwrap();
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn lencode_safe_int_decode_strict_rejects_padded_bytes_path() {
+    let value = SafeInt::from_raw(BigInt::from(1u8) << (8 * LENCODE_MAX_VARINT_BYTES));
+    let mut payload = lencode_zigzag_encode_bigint(&value.raw()).to_bytes_le();
+    payload.push(0);
+    let mut buf = vec![LENCODE_SAFE_INT_VARIANT_BYTES];
+    payload.encode(&mut buf).unwrap();
+
+    let err = SafeInt::decode_strict(&mut Cursor::new(&buf)).unwrap_err();
+    assert!(matches!(err, Error::InvalidData));
+    assert_eq!(SafeInt::decode(&mut Cursor::new(&buf)).unwrap(), value);
+}
+
+#[test]
+#[allow(deprecated)]
+fn lencode_safe_int_dedupe_roundtrip() {
+    let supply = SafeInt::from(21_000_000_000_000_000_000_000_000u128);
+    let huge = SafeInt::from_raw(BigInt::from(7u8) << 1_000usize);
+    let values = [
+        supply.clone(),
+        SafeInt::from(42),
+        huge.clone(),
+        supply.clone(),
+        SafeInt::from(42),
+        huge.clone(),
+        -supply.clone(),
+    ];
+
+    let mut encoder = DedupeEncoder::new();
+    let mut buf = Vec::new();
+    for value in &values {
+        value.encode_ext(&mut buf, Some(&mut encoder)).unwrap();
+    }
+    // Only the three distinct large values enter the table; `42` stays inline.
+    assert_eq!(encoder.len(), 3);
+
+    let mut plain = Vec::new();
+    for value in &values {
+        value.encode(&mut plain).unwrap();
+    }
+    assert!(buf.len() < plain.len());
+
+    let mut decoder = DedupeDecoder::new();
+    let mut cursor = Cursor::new(&buf);
+    for value in &values {
+        let decoded = SafeInt::decode_ext(&mut cursor, Some(&mut decoder)).unwrap();
+        assert_eq!(&decoded, value);
+    }
+}
+
+#[test]
+fn lencode_safe_int_dedupe_keeps_small_values_inline() {
+    let mut encoder = DedupeEncoder::new();
+    let mut deduped = Vec::new();
+    let mut plain = Vec::new();
+    let value = SafeInt::from(u64::MAX / 2);
+    value.encode_ext(&mut deduped, Some(&mut encoder)).unwrap();
+    value.encode(&mut plain).unwrap();
+    assert_eq!(deduped, plain);
+    assert!(encoder.is_empty());
+}
+
+#[test]
+fn lencode_safe_int_dedupe_reference_requires_decoder() {
+    let value = SafeInt::from(u128::MAX);
+    let mut encoder = DedupeEncoder::new();
+    let mut buf = Vec::new();
+    value.encode_ext(&mut buf, Some(&mut encoder)).unwrap();
+    value.encode_ext(&mut buf, Some(&mut encoder)).unwrap();
+    assert_eq!(buf[0], LENCODE_SAFE_INT_VARIANT_DEDUPE);
+
+    let err = SafeInt::decode(&mut Cursor::new(&buf)).unwrap_err();
+    assert!(matches!(err, Error::InvalidData));
+    let err = SafeInt::decode_strict(&mut Cursor::new(&buf)).unwrap_err();
+    assert!(matches!(err, Error::InvalidData));
+}
+
 #[test]
 fn test_log10() {
     let scale = SafeInt::from(1_000_000_000_000_000_000i128);
@@ -1,26 +1,94 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
-//! Safe, non-panicking numeric primitives built on top of pure-Rust `num-bigint` (alloc-only).
+//! Safe, non-panicking numeric primitives built on top of pure-Rust `num-bigint`.
 //!
-//! `std` support is enabled by default; disable default features to use `no_std` + `alloc`.
+//! `std` support is enabled by default. Disable default features and enable `alloc` to use
+//! `no_std` + `alloc`; with neither, only the fixed-capacity [`SafeIntN`] and [`SafeDecN`] are
+//! built and the crate needs no allocator.
 //!
 //! `SafeInt` and `SafeDec` implement `lencode::Encode`/`Decode`. The wire format uses a
 //! compact, little-endian varint header for values with up to 63 payload bytes, and
 //! falls back to lencode's `Vec<u8>` encoding for larger magnitudes.
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+/// Multiplication and division of large magnitudes through a selectable backend.
+#[cfg(feature = "alloc")]
+mod backend;
 /// Fixed-precision decimal support built on `SafeInt`.
+#[cfg(feature = "alloc")]
 pub mod decimal;
+/// Subquadratic conversions between digit strings and big integers.
+#[cfg(feature = "alloc")]
+mod digits;
+/// Error types shared by every numeric type.
+mod error;
+/// Fixed-capacity integers and decimals stored inline, without heap allocation.
+pub mod fixed;
+/// Localized formatting and parsing with digit grouping and custom separators.
+#[cfg(feature = "alloc")]
+pub mod format;
+/// Compact human-readable rendering with magnitude prefixes and number words.
+#[cfg(feature = "alloc")]
+pub mod humanize;
 /// Arbitrary-precision integer support and helpers.
+#[cfg(feature = "alloc")]
 pub mod integer;
 /// Parsers for `SafeInt` and `SafeDec` literals.
+#[cfg(feature = "alloc")]
 pub mod parsing;
+/// Fixed-width integer wrappers that promote to `SafeInt` instead of overflowing.
+#[cfg(feature = "alloc")]
+pub mod primitive;
+/// Rounding strategies shared by the integer and decimal types.
+mod rounding;
+/// Non-negative integers and decimals whose subtraction returns `Option`.
+#[cfg(feature = "alloc")]
+pub mod unsigned;
+/// Header layout of the lencode wire format, shared with the allocation-free encoders.
+mod wire;
 
 /// Re-export of the fixed-precision decimal type.
+#[cfg(feature = "alloc")]
 pub use decimal::SafeDec;
+/// Re-export of the error returned by the `TryFrom` conversions into primitive integers.
+pub use error::ConversionError;
+/// Re-export of the structured error returned when parsing fails.
+pub use error::ParseError;
+/// Re-export of the fixed-capacity decimal type.
+pub use fixed::SafeDecN;
+/// Re-export of the fixed-capacity integer type.
+pub use fixed::SafeIntN;
+/// Re-export of the digit grouping styles used by localized formatting.
+#[cfg(feature = "alloc")]
+pub use format::Grouping;
+/// Re-export of the separator and grouping settings for localized formatting and parsing.
+#[cfg(feature = "alloc")]
+pub use format::NumberStyle;
+/// Re-export of the notations used by compact human-readable formatting.
+#[cfg(feature = "alloc")]
+pub use humanize::Notation;
 /// Re-export of the arbitrary-precision integer type.
+#[cfg(feature = "alloc")]
 pub use integer::SafeInt;
+/// Re-export of the options for lenient decimal parsing.
+#[cfg(feature = "alloc")]
+pub use parsing::ParseOptions;
+/// Re-export of the fixed-width integer wrappers.
+#[cfg(feature = "alloc")]
+pub use primitive::{
+    SafeI8, SafeI16, SafeI32, SafeI64, SafeI128, SafeIsize, SafeU8, SafeU16, SafeU32, SafeU64,
+    SafeU128, SafeUsize,
+};
+/// Re-export of the rounding strategy shared by integer and decimal operations.
+pub use rounding::RoundingMode;
+/// Re-export of the non-negative decimal type.
+#[cfg(feature = "alloc")]
+pub use unsigned::SafeUdec;
+/// Re-export of the non-negative integer type.
+#[cfg(feature = "alloc")]
+pub use unsigned::SafeUint;
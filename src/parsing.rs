@@ -1,13 +1,43 @@
-use quoth::{
-    Parsable, ParsableExt, ParseStream, Span, Spanned,
-    parsable::{Exact, Nothing},
-};
+use quoth::{Parsable, ParsableExt, ParseStream, Span, Spanned};
 
-use crate::SafeInt;
+use crate::{SafeInt, digits, integer::pow10};
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
+
+pub use crate::error::{MAX_EXPONENT, ParseError};
+
+impl ParseError {
+    /// Converts to a `quoth::Error` whose span points into `stream`'s source.
+    fn into_quoth(self, stream: &ParseStream) -> quoth::Error {
+        let span = match self.index() {
+            Some(index) => {
+                let end = stream.remaining_span().byte_range().end;
+                Span::new(stream.source().clone(), index..end.min(index + 1))
+            }
+            None => stream.current_span(),
+        };
+        quoth::Error::new(span, self)
+    }
+}
+
+impl From<quoth::Error> for ParseError {
+    /// Wraps the error as [`ParseError::Other`], keeping its message and the start of its span.
+    fn from(error: quoth::Error) -> Self {
+        ParseError::Other {
+            index: error.span().byte_range().start,
+            message: error.message().to_string(),
+        }
+    }
+}
+
+impl From<ParseError> for quoth::Error {
+    /// Produces an error with a blank span; [`ParseError::index`] is kept in the message.
+    fn from(error: ParseError) -> Self {
+        quoth::Error::new(Span::blank(), error)
+    }
+}
 
 /// Parsed representation of a fixed-scale decimal literal.
 ///
@@ -31,53 +61,167 @@ impl<const D: usize> Spanned for ParsedSafeDec<D> {
 impl<const D: usize> Parsable for ParsedSafeDec<D> {
     fn parse(stream: &mut ParseStream) -> quoth::Result<Self> {
         let start_position = stream.position;
-        let is_neg = if stream.next_char()? == '-' {
-            stream.consume(1)?;
-            true
-        } else {
-            false
-        };
-        let mut major_digits = Vec::new();
-        major_digits.push(stream.parse_digit()?);
-        while let Ok(digit) = stream.parse_digit() {
-            major_digits.push(digit);
+        let (raw, decimals) = parse_safe_dec_digits::<D>(stream, ParseOptions::strict())
+            .map_err(|err| err.into_quoth(stream))?;
+        Ok(ParsedSafeDec {
+            raw,
+            decimals,
+            span: Span::new(stream.source().clone(), start_position..stream.position),
+        })
+    }
+}
+
+/// Options controlling how leniently [`SafeDec`](crate::SafeDec) literals are parsed.
+///
+/// The default is strict: an optional `-`, at least one integer digit, a `.` and at least one
+/// fractional digit (the `.` and fraction are omitted for `SafeDec<0>`), with nothing else
+/// around them. An exponent such as `e-4` may follow in every mode, in which case the fraction
+/// is optional and the value only has to be exactly representable at scale `D`.
+/// [`ParseOptions::lenient`] enables every relaxation.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{ParseOptions, SafeDec};
+///
+/// let options = ParseOptions {
+///     group_separator: Some(','),
+///     ..ParseOptions::lenient()
+/// };
+/// let parsed = SafeDec::<2>::parse_with(" +1,000.5 ", options).unwrap();
+/// assert_eq!(parsed.to_string(), "1000.50");
+/// assert!(SafeDec::<2>::parse_with("1,000.5", ParseOptions::default()).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    /// Accept a missing fractional part, as in `"5"` or `"5."`.
+    pub optional_fraction: bool,
+    /// Accept a missing integer part, as in `".5"` or `"-.5"`.
+    pub optional_integer: bool,
+    /// Accept a leading `+` sign.
+    pub allow_plus: bool,
+    /// Character allowed between two digits to group them, such as `_` in `"1_000.50"`.
+    pub group_separator: Option<char>,
+    /// Ignore leading and trailing whitespace.
+    pub trim_whitespace: bool,
+}
+
+impl ParseOptions {
+    /// The strict default: the format produced by `Display`, optionally in scientific notation.
+    pub const fn strict() -> Self {
+        ParseOptions {
+            optional_fraction: false,
+            optional_integer: false,
+            allow_plus: false,
+            group_separator: None,
+            trim_whitespace: false,
         }
-        stream.parse_value(Exact::from("."))?;
-        let mut minor_digits = Vec::new();
-        minor_digits.push(stream.parse_digit()?);
-        while let Ok(digit) = stream.parse_digit() {
-            minor_digits.push(digit);
+    }
+
+    /// Accepts optional integer and fractional parts, a leading `+`, `_` digit separators and
+    /// surrounding whitespace.
+    pub const fn lenient() -> Self {
+        ParseOptions {
+            optional_fraction: true,
+            optional_integer: true,
+            allow_plus: true,
+            group_separator: Some('_'),
+            trim_whitespace: true,
+        }
+    }
+}
+
+/// Parses a complete decimal literal, returning the value scaled to `D` and the number of
+/// fractional digits present in the input.
+fn parse_safe_dec_digits<const D: usize>(
+    stream: &mut ParseStream,
+    options: ParseOptions,
+) -> Result<(SafeInt, usize), ParseError> {
+    if options.trim_whitespace {
+        skip_whitespace(stream);
+    }
+    check_not_empty(stream)?;
+    let is_neg = parse_sign(stream, options.allow_plus);
+    let separator = options.group_separator;
+    let major = if options.optional_integer && stream.next_char().is_ok_and(|c| c == '.') {
+        Vec::new()
+    } else {
+        parse_digit_run(stream, 10, separator)?
+    };
+    let has_point = match stream.next_char() {
+        Ok('.') => {
+            stream.position += 1;
+            true
         }
-        while minor_digits.len() < D {
-            minor_digits.push(0);
+        Ok('e' | 'E') => false,
+        Ok(c) if c.is_ascii_alphanumeric() => {
+            return Err(ParseError::InvalidDigit {
+                index: stream.position,
+                found: c,
+            });
         }
-        if minor_digits.len() > D {
-            return Err(quoth::Error::new(
-                stream.current_span(),
-                "unexpected extra digit",
-            ));
+        _ if D == 0 || options.optional_fraction => false,
+        _ => {
+            return Err(ParseError::MissingFractionalDigits {
+                index: stream.position,
+            });
         }
-
-        let num_minor_digits = minor_digits.len();
-
-        let mut digits = Vec::new();
-        digits.append(&mut major_digits);
-        digits.append(&mut minor_digits);
-        let mut raw = SafeInt::from(0);
-        for &d in &digits {
-            raw *= 10;
-            raw += d;
+    };
+    let minor = if has_point {
+        match parse_digit_run(stream, 10, separator) {
+            Ok(minor) => minor,
+            Err(ParseError::MissingDigits { index }) => {
+                if options.optional_fraction && !major.is_empty() {
+                    Vec::new()
+                } else {
+                    return Err(ParseError::MissingFractionalDigits { index });
+                }
+            }
+            Err(ParseError::InvalidDigit { index, found }) if !found.is_ascii_alphanumeric() => {
+                if options.optional_fraction && !major.is_empty() {
+                    Vec::new()
+                } else {
+                    return Err(ParseError::MissingFractionalDigits { index });
+                }
+            }
+            Err(err) => return Err(err),
         }
-        if is_neg {
-            raw = -raw;
+    } else {
+        Vec::new()
+    };
+    let exponent = parse_exponent(stream)?;
+    if options.trim_whitespace {
+        let mut rest = stream.fork();
+        skip_whitespace(&mut rest);
+        if rest.next_char().is_err() {
+            *stream = rest;
         }
-        stream.parse::<Nothing>()?;
-        Ok(ParsedSafeDec {
-            raw,
-            decimals: num_minor_digits,
-            span: Span::new(stream.source().clone(), start_position..stream.position),
-        })
     }
+    check_end(stream)?;
+    if exponent.is_none() && minor.len() > D {
+        return Err(ParseError::TooManyFractionalDigits {
+            expected: D,
+            actual: minor.len(),
+        });
+    }
+    let exponent = exponent.unwrap_or(0);
+    let decimals = (minor.len() as i64 - exponent).clamp(0, D as i64) as usize;
+    let fraction_len = minor.len();
+    let mut digits = major;
+    digits.extend(minor);
+    let mut raw = scale_digits(&digits, fraction_len, exponent, D)?;
+    if is_neg {
+        raw = -raw;
+    }
+    Ok((raw, decimals))
+}
+
+/// Parses a string into a decimal scaled to `D`.
+pub(crate) fn parse_safe_dec<const D: usize>(
+    s: &str,
+    options: ParseOptions,
+) -> Result<SafeInt, ParseError> {
+    let mut stream = ParseStream::from(s);
+    parse_safe_dec_digits::<D>(&mut stream, options).map(|(raw, _)| raw)
 }
 
 /// Parsed representation of an integer literal.
@@ -100,30 +244,225 @@ impl Spanned for ParsedSafeInt {
 impl Parsable for ParsedSafeInt {
     fn parse(stream: &mut ParseStream) -> quoth::Result<Self> {
         let start_position = stream.position;
-        let is_neg = if stream.next_char()? == '-' {
-            stream.consume(1)?;
+        let value = parse_safe_int_digits(stream).map_err(|err| err.into_quoth(stream))?;
+        Ok(ParsedSafeInt {
+            value,
+            span: Span::new(stream.source().clone(), start_position..stream.position),
+        })
+    }
+}
+
+/// Parses a complete integer literal with an optional `0x`/`0o`/`0b` prefix.
+fn parse_safe_int_digits(stream: &mut ParseStream) -> Result<SafeInt, ParseError> {
+    check_not_empty(stream)?;
+    let is_neg = parse_sign(stream, false);
+    let radix = if stream.peek_istr("0x") {
+        16
+    } else if stream.peek_istr("0o") {
+        8
+    } else if stream.peek_istr("0b") {
+        2
+    } else {
+        10
+    };
+    if radix != 10 {
+        stream.position += 2;
+    }
+    let mut digits = parse_digit_run(stream, radix, None)?;
+    if radix != 10 {
+        check_end(stream)?;
+        let mut value = SafeInt::from_big(digits::from_digits(&digits, radix).into());
+        if is_neg {
+            value = -value;
+        }
+        return Ok(value);
+    }
+    // A fractional part is only allowed in exponent form, where it can still be integral.
+    let mut fraction_len = 0;
+    let mut rest = stream.fork();
+    if rest.next_char().is_ok_and(|c| c == '.') {
+        rest.position += 1;
+        if let Ok(fraction) = parse_digit_run(&mut rest, 10, None)
+            && rest.next_char().is_ok_and(|c| c == 'e' || c == 'E')
+        {
+            fraction_len = fraction.len();
+            digits.extend(fraction);
+            *stream = rest;
+        }
+    }
+    let exponent = parse_exponent(stream)?.unwrap_or(0);
+    check_end(stream)?;
+    let mut value = scale_digits(&digits, fraction_len, exponent, 0)?;
+    if is_neg {
+        value = -value;
+    }
+    Ok(value)
+}
+
+/// Parses an optional `e`/`E` exponent suffix with an optional sign.
+fn parse_exponent(stream: &mut ParseStream) -> Result<Option<i64>, ParseError> {
+    if !stream.next_char().is_ok_and(|c| c == 'e' || c == 'E') {
+        return Ok(None);
+    }
+    stream.position += 1;
+    let negative = match stream.next_char() {
+        Ok('-') => {
+            stream.position += 1;
             true
-        } else {
+        }
+        Ok('+') => {
+            stream.position += 1;
             false
-        };
-        let mut digits = Vec::new();
-        digits.push(stream.parse_digit()?);
-        while let Ok(digit) = stream.parse_digit() {
-            digits.push(digit);
         }
-        let mut raw = SafeInt::from(0);
-        for &d in &digits {
-            raw *= 10;
-            raw += d;
+        _ => false,
+    };
+    let index = stream.position;
+    let digits = parse_digit_run(stream, 10, None)?;
+    let mut magnitude = 0i64;
+    for digit in digits {
+        magnitude = magnitude * 10 + i64::from(digit);
+        if magnitude > i64::from(MAX_EXPONENT) {
+            return Err(ParseError::ExponentOutOfRange { index });
         }
-        if is_neg {
-            raw = -raw;
+    }
+    Ok(Some(if negative { -magnitude } else { magnitude }))
+}
+
+/// Computes `digits * 10^(exponent - fraction_len)` scaled by `10^scale`, where `digits` are the
+/// decimal digits of the mantissa and the last `fraction_len` of them follow the decimal point.
+/// Fails if the result is not a whole number at that scale.
+fn scale_digits(
+    digits: &[u8],
+    fraction_len: usize,
+    exponent: i64,
+    scale: usize,
+) -> Result<SafeInt, ParseError> {
+    let mantissa = SafeInt::from_big(digits::from_digits(digits, 10).into());
+    let shift = scale as i64 + exponent - fraction_len as i64;
+    if shift >= 0 {
+        return Ok(mantissa * &*pow10(shift as usize));
+    }
+    if mantissa.is_zero() {
+        return Ok(mantissa);
+    }
+    let excess = shift.unsigned_abs() as usize;
+    let trailing_zeros = digits.iter().rev().take_while(|&&d| d == 0).count();
+    if trailing_zeros < excess {
+        return Err(ParseError::TooManyFractionalDigits {
+            expected: scale,
+            actual: scale + excess - trailing_zeros,
+        });
+    }
+    let significant = &digits[..digits.len() - excess];
+    Ok(SafeInt::from_big(
+        digits::from_digits(significant, 10).into(),
+    ))
+}
+
+/// Parses a string into an integer.
+pub(crate) fn parse_safe_int(s: &str) -> Result<SafeInt, ParseError> {
+    parse_safe_int_digits(&mut ParseStream::from(s))
+}
+
+/// Parses an optionally negative integer written in `radix`, with no base prefix.
+pub(crate) fn parse_safe_int_radix(s: &str, radix: u32) -> Result<SafeInt, ParseError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseError::UnsupportedRadix { radix });
+    }
+    let mut stream = ParseStream::from(s);
+    check_not_empty(&stream)?;
+    let is_neg = parse_sign(&mut stream, false);
+    let digits = parse_digit_run(&mut stream, radix, None)?;
+    check_end(&stream)?;
+    let mut value = SafeInt::from_big(digits::from_digits(&digits, radix).into());
+    if is_neg {
+        value = -value;
+    }
+    Ok(value)
+}
+
+fn check_not_empty(stream: &ParseStream) -> Result<(), ParseError> {
+    if stream.next_char().is_err() {
+        return Err(ParseError::Empty);
+    }
+    Ok(())
+}
+
+/// Consumes an optional leading `-` (or `+` when `allow_plus` is set), returning whether the
+/// value is negative.
+fn parse_sign(stream: &mut ParseStream, allow_plus: bool) -> bool {
+    match stream.next_char() {
+        Ok('-') => {
+            stream.position += 1;
+            true
         }
-        stream.parse::<Nothing>()?;
-        Ok(ParsedSafeInt {
-            value: raw,
-            span: Span::new(stream.source().clone(), start_position..stream.position),
-        })
+        Ok('+') if allow_plus => {
+            stream.position += 1;
+            false
+        }
+        _ => false,
+    }
+}
+
+fn skip_whitespace(stream: &mut ParseStream) {
+    while stream.next_char().is_ok_and(char::is_whitespace) {
+        stream.position += 1;
+    }
+}
+
+/// Parses one or more digits in `radix` (2 through 36, case-insensitive), returning their
+/// values most significant first. A `separator` is skipped when it sits between two digits.
+///
+/// The run ends at the first character that is not a digit in `radix`; a letter or digit there
+/// is reported as [`ParseError::InvalidDigit`] only if no digits were read.
+fn parse_digit_run(
+    stream: &mut ParseStream,
+    radix: u32,
+    separator: Option<char>,
+) -> Result<Vec<u8>, ParseError> {
+    let mut digits = Vec::new();
+    while let Ok(c) = stream.next_char() {
+        if let Some(digit) = c.to_digit(radix) {
+            digits.push(digit as u8);
+            stream.position += 1;
+            continue;
+        }
+        if separator != Some(c) || digits.is_empty() {
+            break;
+        }
+        let mut after = stream.fork();
+        after.position += 1;
+        if !after.next_char().is_ok_and(|next| next.is_digit(radix)) {
+            break;
+        }
+        stream.position += 1;
+    }
+    if digits.is_empty() {
+        return Err(match stream.next_char() {
+            Ok(found) => ParseError::InvalidDigit {
+                index: stream.position,
+                found,
+            },
+            Err(_) => ParseError::MissingDigits {
+                index: stream.position,
+            },
+        });
+    }
+    Ok(digits)
+}
+
+/// Requires the number to end here: letters and digits continuing the number are invalid
+/// digits, while anything else is trailing text.
+fn check_end(stream: &ParseStream) -> Result<(), ParseError> {
+    match stream.next_char() {
+        Err(_) => Ok(()),
+        Ok(found) if found.is_ascii_alphanumeric() => Err(ParseError::InvalidDigit {
+            index: stream.position,
+            found,
+        }),
+        Ok(_) => Err(ParseError::TrailingCharacters {
+            index: stream.position,
+        }),
     }
 }
 
@@ -181,6 +520,7 @@ fn test_parse_safe_dec_invalid_no_minor_digits() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_parse_safe_int_valid_positive() {
     assert_eq!(
         ParseStream::from("123456")
@@ -248,3 +588,200 @@ fn test_parse_safe_int_invalid_non_alpha() {
         .parse::<ParsedSafeInt>()
         .unwrap_err();
 }
+
+#[test]
+fn test_parse_safe_int_prefixed_radix() {
+    let parse = |s: &str| {
+        ParseStream::from(s)
+            .parse::<ParsedSafeInt>()
+            .map(|p| p.value)
+    };
+    assert_eq!(parse("0xff").unwrap(), 255);
+    assert_eq!(parse("0XFF").unwrap(), 255);
+    assert_eq!(parse("-0x10").unwrap(), -16);
+    assert_eq!(parse("0o777").unwrap(), 511);
+    assert_eq!(parse("0b1011").unwrap(), 11);
+    assert_eq!(parse("-0b1").unwrap(), -1);
+    assert_eq!(
+        parse("0xffffffffffffffffffffffffffffffffff").unwrap(),
+        SafeInt::from_str_radix("ffffffffffffffffffffffffffffffffff", 16).unwrap()
+    );
+    parse("0x").unwrap_err();
+    parse("0b102").unwrap_err();
+    parse("0o8").unwrap_err();
+    parse("0xg").unwrap_err();
+    parse("0x-1").unwrap_err();
+}
+
+#[test]
+fn test_parse_error_variants() {
+    use crate::SafeDec;
+
+    let dec = |s: &str| s.parse::<SafeDec<2>>().map(|_| ()).unwrap_err();
+    assert_eq!(dec(""), ParseError::Empty);
+    assert_eq!(dec("-"), ParseError::MissingDigits { index: 1 });
+    assert_eq!(
+        dec("1a.00"),
+        ParseError::InvalidDigit {
+            index: 1,
+            found: 'a'
+        }
+    );
+    assert_eq!(
+        dec(".50"),
+        ParseError::InvalidDigit {
+            index: 0,
+            found: '.'
+        }
+    );
+    assert_eq!(
+        dec("1.2345"),
+        ParseError::TooManyFractionalDigits {
+            expected: 2,
+            actual: 4
+        }
+    );
+    assert_eq!(dec("12"), ParseError::MissingFractionalDigits { index: 2 });
+    assert_eq!(dec("12."), ParseError::MissingFractionalDigits { index: 3 });
+    assert_eq!(
+        dec("12 .5"),
+        ParseError::MissingFractionalDigits { index: 2 }
+    );
+    assert_eq!(dec("1.25 USD"), ParseError::TrailingCharacters { index: 4 });
+    assert_eq!(dec("1.2.5"), ParseError::TrailingCharacters { index: 3 });
+    assert_eq!(
+        dec("1.25x"),
+        ParseError::InvalidDigit {
+            index: 4,
+            found: 'x'
+        }
+    );
+
+    let int = |s: &str| s.parse::<SafeInt>().unwrap_err();
+    assert_eq!(int(""), ParseError::Empty);
+    assert_eq!(int("0x"), ParseError::MissingDigits { index: 2 });
+    assert_eq!(
+        int("12a4"),
+        ParseError::InvalidDigit {
+            index: 2,
+            found: 'a'
+        }
+    );
+    assert_eq!(int("12 "), ParseError::TrailingCharacters { index: 2 });
+    assert_eq!(
+        SafeInt::from_str_radix("10", 37).unwrap_err(),
+        ParseError::UnsupportedRadix { radix: 37 }
+    );
+    assert_eq!(
+        int("1.5").to_string(),
+        "unexpected trailing characters at index 1"
+    );
+}
+
+#[test]
+fn test_parse_error_quoth_conversions() {
+    let err = ParseStream::from("1.2345")
+        .parse::<ParsedSafeDec<2>>()
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "too many fractional digits: expected at most 2, found 4"
+    );
+    let err = ParseStream::from("12x4")
+        .parse::<ParsedSafeInt>()
+        .unwrap_err();
+    assert_eq!(err.span().byte_range(), &(2..3));
+    assert_eq!(
+        ParseError::from(err),
+        ParseError::Other {
+            index: 2,
+            message: "invalid digit 'x' at index 2".to_string()
+        }
+    );
+
+    let quoth_err = quoth::Error::from(ParseError::MissingDigits { index: 1 });
+    assert_eq!(
+        quoth_err.message(),
+        "expected digit at index 1, found end of input"
+    );
+}
+
+#[test]
+fn test_parse_options() {
+    use crate::SafeDec;
+
+    let lenient = ParseOptions::lenient();
+    let parse = |s: &str| SafeDec::<2>::parse_with(s, lenient).map(|d| d.to_string());
+    assert_eq!(parse("5").unwrap(), "5.00");
+    assert_eq!(parse("5.").unwrap(), "5.00");
+    assert_eq!(parse(".5").unwrap(), "0.50");
+    assert_eq!(parse("-.5").unwrap(), "-0.50");
+    assert_eq!(parse("+1.2").unwrap(), "1.20");
+    assert_eq!(parse("1_000.50").unwrap(), "1000.50");
+    assert_eq!(parse("1_000_000").unwrap(), "1000000.00");
+    assert_eq!(parse("0.0_5").unwrap(), "0.05");
+    assert_eq!(parse(" \t3.0 \n").unwrap(), "3.00");
+    assert_eq!(
+        parse("."),
+        Err(ParseError::MissingFractionalDigits { index: 1 })
+    );
+    assert_eq!(parse("   "), Err(ParseError::Empty));
+    assert_eq!(
+        parse("1__000"),
+        Err(ParseError::TrailingCharacters { index: 1 })
+    );
+    assert_eq!(
+        parse("_1"),
+        Err(ParseError::InvalidDigit {
+            index: 0,
+            found: '_'
+        })
+    );
+    assert_eq!(
+        parse("1_"),
+        Err(ParseError::TrailingCharacters { index: 1 })
+    );
+    assert_eq!(
+        parse(" 3.0 x"),
+        Err(ParseError::TrailingCharacters { index: 4 })
+    );
+    assert_eq!(
+        parse("+-1"),
+        Err(ParseError::InvalidDigit {
+            index: 1,
+            found: '-'
+        })
+    );
+    assert_eq!(
+        parse("1.234"),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 2,
+            actual: 3
+        })
+    );
+
+    // Each relaxation is independent.
+    let only_plus = ParseOptions {
+        allow_plus: true,
+        ..ParseOptions::strict()
+    };
+    SafeDec::<2>::parse_with("+1.2", only_plus).unwrap();
+    SafeDec::<2>::parse_with("1", only_plus).unwrap_err();
+    SafeDec::<2>::parse_with(" 1.2", only_plus).unwrap_err();
+    for strict_rejects in ["5", "+1.2", "1_000.50", " 3.0 ", ".5", "5."] {
+        strict_rejects.parse::<SafeDec<2>>().unwrap_err();
+    }
+
+    // Whole-unit decimals parse without a fractional part, even in strict mode.
+    assert_eq!("42".parse::<SafeDec<0>>().unwrap(), 42);
+    assert_eq!("-7".parse::<SafeDec<0>>().unwrap(), -7);
+    assert_eq!(
+        "42.0".parse::<SafeDec<0>>(),
+        Err(ParseError::TooManyFractionalDigits {
+            expected: 0,
+            actual: 1
+        })
+    );
+    assert_eq!(SafeDec::<0>::parse_with("42.", lenient).unwrap(), 42);
+    assert_eq!(SafeDec::<0>::parse_with(" +1_000 ", lenient).unwrap(), 1000);
+}
@@ -0,0 +1,519 @@
+//! Fixed-width integer wrappers that never overflow.
+//!
+//! Each wrapper holds one primitive integer. Its arithmetic operators return the exact result as
+//! a [`SafeInt`], so a sum or product past the primitive's range is promoted instead of wrapping
+//! or panicking (results that fit stay inline in the `SafeInt` and do not allocate). Bitwise
+//! operators between two wrappers of the same type cannot overflow and keep the narrow type.
+//! Code that has to stay at the fixed width uses the `checked_*`, `saturating_*` and
+//! `wrapping_*` methods instead, and narrows a promoted result back with `TryFrom`.
+
+use crate::{ConversionError, RoundingMode, SafeDec, SafeInt, parsing::ParseError};
+#[cfg(test)]
+use alloc::format;
+#[cfg(test)]
+use alloc::string::ToString;
+#[cfg(test)]
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt, ops::*, str::FromStr};
+use lencode::dedupe::{DedupeDecoder, DedupeEncoder};
+#[cfg(test)]
+use lencode::io::Cursor;
+use lencode::io::{Error, Read, Write};
+use lencode::{Decode, Encode};
+
+macro_rules! impl_safe_primitive_ops {
+    ($name:ident, $prim:ty, [$($trait:ident, $method:ident);*]) => {
+        $(
+            impl $trait for $name {
+                type Output = <SafeInt as $trait<$prim>>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $name) -> Self::Output {
+                    SafeInt::from(self.0).$method(other.0)
+                }
+            }
+
+            impl $trait<SafeInt> for $name {
+                type Output = <$prim as $trait<SafeInt>>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeInt) -> Self::Output {
+                    self.0.$method(other)
+                }
+            }
+
+            impl $trait<&SafeInt> for $name {
+                type Output = <$prim as $trait<SafeInt>>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeInt) -> Self::Output {
+                    self.0.$method(other)
+                }
+            }
+
+            impl $trait<$name> for SafeInt {
+                type Output = <SafeInt as $trait<$prim>>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $name) -> Self::Output {
+                    self.$method(other.0)
+                }
+            }
+
+            impl $trait<$name> for &SafeInt {
+                type Output = <SafeInt as $trait<$prim>>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $name) -> Self::Output {
+                    self.$method(other.0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_safe_primitive_bit_ops {
+    ($name:ident, [$($trait:ident, $method:ident);*]) => {
+        $(
+            impl $trait for $name {
+                type Output = $name;
+
+                #[inline(always)]
+                fn $method(self, other: $name) -> $name {
+                    $name(self.0.$method(other.0))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_safe_primitive_modes {
+    ($name:ident, $prim:ty, [$($checked:ident),*], [$($other:ident),*]) => {
+        impl $name {
+            $(
+                #[doc = concat!(
+                    "Same as [`", stringify!($prim), "::", stringify!($checked),
+                    "`]: `None` instead of leaving the range of `", stringify!($prim), "`."
+                )]
+                #[inline(always)]
+                pub const fn $checked(self, other: $name) -> Option<$name> {
+                    match self.0.$checked(other.0) {
+                        Some(value) => Some($name(value)),
+                        None => None,
+                    }
+                }
+            )*
+            $(
+                #[doc = concat!(
+                    "Same as [`", stringify!($prim), "::", stringify!($other), "`]."
+                )]
+                #[inline(always)]
+                pub const fn $other(self, other: $name) -> $name {
+                    $name(self.0.$other(other.0))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! define_safe_primitives {
+    ($($name:ident => $prim:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "`", stringify!($prim), "` whose arithmetic operators promote to [`SafeInt`] ",
+                "instead of overflowing."
+            )]
+            ///
+            /// `+`, `-`, `*` and unary `-` return the exact [`SafeInt`]; `/` and `%` return
+            /// `Option<SafeInt>`, with `None` on division by zero. The same operators work with a
+            /// `SafeInt` on either side. `&`, `|` and `^` between two wrappers keep the wrapper
+            /// type, and the `checked_*`, `saturating_*` and `wrapping_*` methods stay at the
+            /// fixed width.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use safe_bigmath::{SafeInt, ", stringify!($name), "};")]
+            ///
+            #[doc = concat!("let max = ", stringify!($name), "::MAX;")]
+            #[doc = concat!("let sum = max + ", stringify!($name), "::new(1);")]
+            #[doc = concat!("assert_eq!(sum, SafeInt::from(", stringify!($prim), "::MAX) + 1);")]
+            #[doc = concat!("assert_eq!(max.checked_add(", stringify!($name), "::new(1)), None);")]
+            #[doc = concat!("assert_eq!(max.saturating_add(", stringify!($name), "::new(1)), max);")]
+            #[doc = concat!("assert!(", stringify!($name), "::try_from(&sum).is_err());")]
+            /// ```
+            #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(transparent)]
+            pub struct $name($prim);
+
+            impl $name {
+                #[doc = concat!("Smallest value, `", stringify!($prim), "::MIN`.")]
+                pub const MIN: $name = $name($prim::MIN);
+                #[doc = concat!("Largest value, `", stringify!($prim), "::MAX`.")]
+                pub const MAX: $name = $name($prim::MAX);
+
+                /// Wraps a primitive value.
+                #[inline(always)]
+                pub const fn new(value: $prim) -> $name {
+                    $name(value)
+                }
+
+                /// Returns the primitive value.
+                #[inline(always)]
+                pub const fn get(self) -> $prim {
+                    self.0
+                }
+            }
+
+            impl_safe_primitive_modes!(
+                $name, $prim,
+                [checked_add, checked_sub, checked_mul, checked_div, checked_rem],
+                [saturating_add, saturating_sub, saturating_mul, wrapping_add, wrapping_sub,
+                    wrapping_mul]
+            );
+
+            impl_safe_primitive_ops!(
+                $name, $prim,
+                [Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem]
+            );
+
+            impl_safe_primitive_bit_ops!(
+                $name,
+                [BitAnd, bitand; BitOr, bitor; BitXor, bitxor]
+            );
+
+            impl Neg for $name {
+                type Output = SafeInt;
+
+                #[inline(always)]
+                fn neg(self) -> SafeInt {
+                    -SafeInt::from(self.0)
+                }
+            }
+
+            impl From<$prim> for $name {
+                #[inline(always)]
+                fn from(value: $prim) -> $name {
+                    $name(value)
+                }
+            }
+
+            impl From<$name> for $prim {
+                #[inline(always)]
+                fn from(value: $name) -> $prim {
+                    value.0
+                }
+            }
+
+            impl From<$name> for SafeInt {
+                #[inline(always)]
+                fn from(value: $name) -> SafeInt {
+                    SafeInt::from(value.0)
+                }
+            }
+
+            impl<const D: usize> From<$name> for SafeDec<D> {
+                #[inline(always)]
+                fn from(value: $name) -> SafeDec<D> {
+                    SafeDec::from_raw_at_scale(value.0, 0, RoundingMode::TowardZero)
+                }
+            }
+
+            impl TryFrom<&SafeInt> for $name {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeInt) -> Result<$name, ConversionError> {
+                    $prim::try_from(value).map($name)
+                }
+            }
+
+            impl TryFrom<SafeInt> for $name {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeInt) -> Result<$name, ConversionError> {
+                    $prim::try_from(&value).map($name)
+                }
+            }
+
+            impl<const D: usize> TryFrom<&SafeDec<D>> for $name {
+                type Error = ConversionError;
+
+                /// Converts whole values only, like the primitive conversions of [`SafeDec`].
+                #[inline(always)]
+                fn try_from(value: &SafeDec<D>) -> Result<$name, ConversionError> {
+                    $prim::try_from(value).map($name)
+                }
+            }
+
+            impl<const D: usize> TryFrom<SafeDec<D>> for $name {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeDec<D>) -> Result<$name, ConversionError> {
+                    $prim::try_from(&value).map($name)
+                }
+            }
+
+            impl PartialEq<$prim> for $name {
+                #[inline(always)]
+                fn eq(&self, other: &$prim) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl PartialOrd<$prim> for $name {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            impl PartialEq<$name> for $prim {
+                #[inline(always)]
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl PartialOrd<$name> for $prim {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+
+            impl PartialEq<SafeInt> for $name {
+                #[inline(always)]
+                fn eq(&self, other: &SafeInt) -> bool {
+                    *other == self.0
+                }
+            }
+
+            impl PartialOrd<SafeInt> for $name {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeInt) -> Option<Ordering> {
+                    other.partial_cmp(&self.0).map(Ordering::reverse)
+                }
+            }
+
+            impl PartialEq<$name> for SafeInt {
+                #[inline(always)]
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl PartialOrd<$name> for SafeInt {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+
+            impl<const D: usize> PartialEq<SafeDec<D>> for $name {
+                #[inline(always)]
+                fn eq(&self, other: &SafeDec<D>) -> bool {
+                    *other == self.0
+                }
+            }
+
+            impl<const D: usize> PartialOrd<SafeDec<D>> for $name {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeDec<D>) -> Option<Ordering> {
+                    other.partial_cmp(&self.0).map(Ordering::reverse)
+                }
+            }
+
+            impl<const D: usize> PartialEq<$name> for SafeDec<D> {
+                #[inline(always)]
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl<const D: usize> PartialOrd<$name> for SafeDec<D> {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+
+            impl fmt::Display for $name {
+                #[inline(always)]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            /// Accepts the same syntax as [`SafeInt`]; values outside the primitive's range
+            /// fail with [`ParseError::OutOfRange`].
+            impl FromStr for $name {
+                type Err = ParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    $name::try_from(&s.parse::<SafeInt>()?).map_err(|_| ParseError::OutOfRange)
+                }
+            }
+
+            /// Same wire format as [`SafeInt`].
+            impl Encode for $name {
+                #[inline(always)]
+                fn encode_ext(
+                    &self,
+                    writer: &mut impl Write,
+                    dedupe_encoder: Option<&mut DedupeEncoder>,
+                ) -> lencode::Result<usize> {
+                    SafeInt::from(self.0).encode_ext(writer, dedupe_encoder)
+                }
+            }
+
+            impl Decode for $name {
+                #[inline(always)]
+                fn decode_ext(
+                    reader: &mut impl Read,
+                    dedupe_decoder: Option<&mut DedupeDecoder>,
+                ) -> lencode::Result<Self> {
+                    let value = SafeInt::decode_ext(reader, dedupe_decoder)?;
+                    $name::try_from(&value).map_err(|_| Error::InvalidData)
+                }
+            }
+        )*
+    };
+}
+
+define_safe_primitives!(
+    SafeU8 => u8,
+    SafeU16 => u16,
+    SafeU32 => u32,
+    SafeU64 => u64,
+    SafeU128 => u128,
+    SafeUsize => usize,
+    SafeI8 => i8,
+    SafeI16 => i16,
+    SafeI32 => i32,
+    SafeI64 => i64,
+    SafeI128 => i128,
+    SafeIsize => isize,
+);
+
+#[test]
+fn test_safe_primitive_promotes() {
+    assert_eq!(SafeU8::MAX + SafeU8::new(1), 256);
+    assert_eq!(SafeU64::new(0) - SafeU64::new(1), -1);
+    assert_eq!(
+        SafeI128::MIN - SafeI128::new(1),
+        SafeInt::from(i128::MIN) - 1
+    );
+    assert_eq!(
+        SafeU128::MAX * SafeU128::MAX,
+        SafeInt::from(u128::MAX) * SafeInt::from(u128::MAX)
+    );
+    assert_eq!(-SafeI64::MIN, SafeInt::from(i64::MAX) + 1);
+    assert_eq!(-SafeU32::new(5), -5);
+    assert_eq!(SafeI8::MIN / SafeI8::new(-1), Some(SafeInt::from(128)));
+    assert_eq!(SafeI8::MIN % SafeI8::new(-1), Some(SafeInt::zero()));
+    assert_eq!(SafeU16::new(7) / SafeU16::new(0), None);
+    assert_eq!(SafeU16::new(7) % SafeU16::new(0), None);
+    assert_eq!(
+        SafeU8::new(0b1100) & SafeU8::new(0b1010),
+        SafeU8::new(0b1000)
+    );
+    assert_eq!(SafeI16::new(-1) ^ SafeI16::new(1), SafeI16::new(-2));
+
+    let big = SafeInt::from(u128::MAX);
+    assert_eq!(SafeU64::MAX + &big, &big + u64::MAX);
+    assert_eq!(big.clone() - SafeU64::MAX, &big - u64::MAX);
+    assert_eq!(&big * SafeI8::new(-2), &big * -2i8);
+    assert_eq!(SafeIsize::new(3) / SafeInt::zero(), None);
+    assert_eq!(big.clone() / SafeU8::new(0), None);
+    assert_eq!((big.clone() % SafeU32::new(10)).unwrap(), 5);
+}
+
+#[test]
+fn test_safe_primitive_modes() {
+    assert_eq!(SafeU8::MAX.checked_add(SafeU8::new(1)), None);
+    assert_eq!(SafeU8::new(3).checked_sub(SafeU8::new(4)), None);
+    assert_eq!(SafeI32::new(6).checked_div(SafeI32::new(0)), None);
+    assert_eq!(SafeI32::MIN.checked_div(SafeI32::new(-1)), None);
+    assert_eq!(
+        SafeI32::new(7).checked_rem(SafeI32::new(2)),
+        Some(SafeI32::new(1))
+    );
+    assert_eq!(SafeU8::new(3).saturating_sub(SafeU8::new(4)), SafeU8::MIN);
+    assert_eq!(SafeI64::MAX.saturating_mul(SafeI64::new(2)), SafeI64::MAX);
+    assert_eq!(SafeU8::MAX.wrapping_add(SafeU8::new(2)), SafeU8::new(1));
+    assert_eq!(SafeI8::MIN.wrapping_sub(SafeI8::new(1)), SafeI8::MAX);
+    assert_eq!(
+        SafeU16::new(300).wrapping_mul(SafeU16::new(300)),
+        SafeU16::new(24_464)
+    );
+}
+
+#[test]
+fn test_safe_primitive_interop() {
+    let value = SafeU64::new(42);
+    assert_eq!(SafeInt::from(value), 42);
+    assert_eq!(u64::from(value), 42);
+    assert_eq!(SafeU64::from(42u64), value);
+    assert_eq!(SafeDec::<2>::from(value).to_string(), "42.00");
+    assert_eq!(SafeDec::<2>::from(SafeI8::new(-3)).to_string(), "-3.00");
+    assert_eq!(SafeU64::try_from(SafeInt::from(42)), Ok(value));
+    assert_eq!(
+        SafeU8::try_from(&SafeInt::from(-1)),
+        Err(ConversionError::Underflow { target: "u8" })
+    );
+    let whole = "42.00".parse::<SafeDec<2>>().unwrap();
+    let fractional = "41.50".parse::<SafeDec<2>>().unwrap();
+    assert_eq!(SafeU64::try_from(&whole), Ok(value));
+    assert_eq!(
+        SafeU64::try_from(fractional.clone()),
+        Err(ConversionError::Fractional { target: "u64" })
+    );
+
+    assert!(value == 42u64);
+    assert!(42u64 == value);
+    assert!(value > 41u64 && 43u64 > value);
+    let (forty_two, huge) = (SafeInt::from(42), SafeInt::from(u128::MAX));
+    assert!(value == forty_two);
+    assert!(forty_two == value);
+    assert!(value < huge);
+    assert!(huge > value);
+    assert!(SafeI128::MIN < SafeInt::zero());
+    assert!(value == whole);
+    assert!(whole == value);
+    assert!(value > fractional);
+    assert!(fractional < value);
+    assert!(SafeI8::new(-1) < SafeDec::<6>::zero());
+}
+
+#[test]
+fn test_safe_primitive_display_parse_and_lencode() {
+    assert_eq!(SafeI16::new(-5).to_string(), "-5");
+    assert_eq!(format!("{:>4}", SafeU8::new(7)), "   7");
+    assert_eq!(format!("{:?}", SafeU8::new(7)), "SafeU8(7)");
+    assert_eq!("0xff".parse::<SafeU8>(), Ok(SafeU8::MAX));
+    assert_eq!("256".parse::<SafeU8>(), Err(ParseError::OutOfRange));
+    assert_eq!("-1".parse::<SafeU64>(), Err(ParseError::OutOfRange));
+    assert!("x".parse::<SafeI32>().is_err());
+
+    for value in [
+        SafeI128::MIN,
+        SafeI128::new(-1),
+        SafeI128::new(0),
+        SafeI128::MAX,
+    ] {
+        let mut buf = Vec::new();
+        value.encode(&mut buf).unwrap();
+        let mut expected = Vec::new();
+        SafeInt::from(value).encode(&mut expected).unwrap();
+        assert_eq!(buf, expected);
+        assert_eq!(SafeI128::decode(&mut Cursor::new(&buf)).unwrap(), value);
+    }
+    let mut buf = Vec::new();
+    SafeInt::from(300).encode(&mut buf).unwrap();
+    assert!(matches!(
+        SafeU8::decode(&mut Cursor::new(&buf)),
+        Err(Error::InvalidData)
+    ));
+}
@@ -0,0 +1,66 @@
+//! Rounding strategies shared by the integer and decimal types.
+
+use core::cmp::Ordering;
+
+/// Rounding strategy used when an exact result has to be reduced to fewer digits.
+///
+/// "Up" and "down" refer to magnitude (away from / toward zero), while `Floor` and `Ceil`
+/// refer to the number line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Discard the remainder (round toward zero).
+    TowardZero,
+    /// Round any non-zero remainder away from zero.
+    AwayFromZero,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round to nearest, ties away from zero.
+    HalfUp,
+    /// Round to nearest, ties toward zero.
+    HalfDown,
+    /// Round to nearest, ties to the even neighbour (banker's rounding).
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Every rounding mode, in declaration order.
+    pub const ALL: [RoundingMode; 7] = [
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+        RoundingMode::HalfEven,
+    ];
+
+    /// Decides whether an inexact quotient moves away from zero. `half` compares twice the
+    /// remainder's magnitude with the divisor's, and is only evaluated by the `Half*` modes.
+    #[inline(always)]
+    pub(crate) fn rounds_away(
+        self,
+        negative: bool,
+        half: impl FnOnce() -> Ordering,
+        quotient_odd: bool,
+    ) -> bool {
+        match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceil => !negative,
+            RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {
+                match half() {
+                    Ordering::Less => false,
+                    Ordering::Greater => true,
+                    Ordering::Equal => match self {
+                        RoundingMode::HalfUp => true,
+                        RoundingMode::HalfDown => false,
+                        _ => quotient_odd,
+                    },
+                }
+            }
+        }
+    }
+}
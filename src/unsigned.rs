@@ -0,0 +1,1741 @@
+//! Non-negative integers and decimals with fallible subtraction.
+//!
+//! [`SafeUint`] and [`SafeUdec`] follow the arithmetic of [`SafeInt`] and [`SafeDec`] for
+//! quantities that can never be negative, such as balances or supplies. Subtraction returns
+//! `Option` and yields `None` where the signed types would go below zero, so an underflow has to
+//! be handled where it happens. There is no `SubAssign` for the same reason.
+//!
+//! Operators and comparisons accept every primitive integer on either side. With a signed
+//! primitive every operator returns `Option`, yielding `None` wherever the signed types would
+//! produce a negative result (`balance + -5` when the balance is below 5, or `balance * -1`
+//! for a non-zero balance), so a negative operand cannot underflow either. The compound
+//! assignment operators that return `SafeUint` only take unsigned operands. Conversions from
+//! `SafeInt` and `SafeDec` fail with [`ConversionError::Underflow`] for negative values.
+//!
+//! The lencode encoding is the `SafeInt` wire format without the zigzag step: the magnitude is
+//! written directly, so values up to 63 take the one-byte form instead of values up to 31.
+
+use crate::{
+    ConversionError, SafeDec, SafeInt, backend, digits,
+    integer::{
+        lencode_decode_biguint_from_tag, lencode_decode_biguint_with_variant,
+        lencode_encode_biguint_with_variant, lencode_read_tag, pow10_unsigned,
+    },
+    parsing::ParseError,
+    wire::{LENCODE_SAFE_INT_DEDUPE_MIN_BYTES, LENCODE_SAFE_INT_VARIANT_DEDUPE},
+};
+#[cfg(test)]
+use alloc::format;
+#[cfg(test)]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt, ops::*, str::FromStr};
+use lencode::dedupe::{DedupeDecoder, DedupeEncoder};
+#[cfg(test)]
+use lencode::io::Cursor;
+use lencode::io::{Read, Write};
+use lencode::pack::Pack;
+use lencode::{Decode, Encode};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+/// Arbitrary-precision non-negative integer.
+///
+/// Addition, multiplication and the bitwise operators return `SafeUint`; subtraction, division
+/// and remainder return `Option<SafeUint>`, yielding `None` on underflow or a zero divisor.
+/// Every operator returns `Option<SafeUint>` for a signed primitive operand, which yields `None`
+/// when the result would be negative.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{SafeInt, SafeUint};
+///
+/// let balance = SafeUint::from(100u32);
+/// assert_eq!((&balance - 30u8).unwrap(), 70);
+/// assert_eq!(&balance - 130u8, None);
+/// assert_eq!((&balance + -30i32).unwrap(), 70);
+/// assert_eq!(&balance + -130i32, None);
+///
+/// let signed = SafeInt::from(balance);
+/// assert_eq!(SafeUint::try_from(-signed).ok(), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SafeUint(BigUint);
+
+impl SafeUint {
+    /// Returns zero.
+    #[inline(always)]
+    pub fn zero() -> SafeUint {
+        SafeUint(BigUint::zero())
+    }
+
+    /// Returns one.
+    #[inline(always)]
+    pub fn one() -> SafeUint {
+        SafeUint(BigUint::one())
+    }
+
+    /// Returns `true` if the value is zero.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the value as little-endian 64-bit limbs, with no trailing zero limbs.
+    ///
+    /// Zero has no limbs. Together with [`SafeUint::from_u64_digits`] this moves values in and
+    /// out of other big-integer libraries without depending on the storage type.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeUint;
+    ///
+    /// assert_eq!(SafeUint::from(1u128 << 64).to_u64_digits(), vec![0, 1]);
+    /// assert_eq!(SafeUint::zero().to_u64_digits(), Vec::<u64>::new());
+    /// ```
+    #[inline(always)]
+    pub fn to_u64_digits(&self) -> Vec<u64> {
+        self.0.to_u64_digits()
+    }
+
+    /// Builds a value from little-endian 64-bit limbs, as returned by
+    /// [`SafeUint::to_u64_digits`]. Trailing zero limbs are allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeUint;
+    ///
+    /// assert_eq!(SafeUint::from_u64_digits(&[0, 1, 0]), 1u128 << 64);
+    /// assert!(SafeUint::from_u64_digits(&[]).is_zero());
+    /// ```
+    pub fn from_u64_digits(digits: &[u64]) -> SafeUint {
+        SafeUint(BigUint::from_slice(
+            &digits
+                .iter()
+                .flat_map(|&digit| [digit as u32, (digit >> 32) as u32])
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Returns the little-endian bytes of the value.
+    ///
+    /// Zero is encoded as `[0]`.
+    #[inline(always)]
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        self.0.to_bytes_le()
+    }
+
+    /// Returns the big-endian bytes of the value.
+    ///
+    /// Zero is encoded as `[0]`.
+    #[inline(always)]
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        self.0.to_bytes_be()
+    }
+
+    /// Interprets `bytes` as an unsigned little-endian integer.
+    #[inline(always)]
+    pub fn from_bytes_le(bytes: &[u8]) -> SafeUint {
+        SafeUint(BigUint::from_bytes_le(bytes))
+    }
+
+    /// Interprets `bytes` as an unsigned big-endian integer.
+    #[inline(always)]
+    pub fn from_bytes_be(bytes: &[u8]) -> SafeUint {
+        SafeUint(BigUint::from_bytes_be(bytes))
+    }
+
+    /// Raises the value to `exp`.
+    #[inline(always)]
+    pub fn pow(self, exp: u32) -> SafeUint {
+        SafeUint(self.0.pow(exp))
+    }
+
+    /// Subtracts `other`, clamping at zero instead of underflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use safe_bigmath::SafeUint;
+    ///
+    /// assert_eq!(SafeUint::from(3u8).saturating_sub(&SafeUint::from(5u8)), 0);
+    /// ```
+    #[inline(always)]
+    pub fn saturating_sub(&self, other: &SafeUint) -> SafeUint {
+        (self - other).unwrap_or_else(SafeUint::zero)
+    }
+
+    /// Returns `|self - other|`.
+    #[inline(always)]
+    pub fn abs_diff(&self, other: &SafeUint) -> SafeUint {
+        if self.0 >= other.0 {
+            SafeUint(&self.0 - &other.0)
+        } else {
+            SafeUint(&other.0 - &self.0)
+        }
+    }
+}
+
+macro_rules! impl_uint_from_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl From<$prim> for SafeUint {
+                #[inline(always)]
+                fn from(value: $prim) -> SafeUint {
+                    SafeUint(BigUint::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_from_primitive!(u8, u16, u32, u64, u128, usize);
+
+impl From<SafeUint> for SafeInt {
+    #[inline(always)]
+    fn from(value: SafeUint) -> SafeInt {
+        SafeInt::from_big(BigInt::from(value.0))
+    }
+}
+
+impl From<&SafeUint> for SafeInt {
+    #[inline(always)]
+    fn from(value: &SafeUint) -> SafeInt {
+        match i128::try_from(&value.0) {
+            Ok(small) => SafeInt::from(small),
+            Err(_) => SafeInt::from_big(BigInt::from(value.0.clone())),
+        }
+    }
+}
+
+impl TryFrom<&SafeInt> for SafeUint {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: &SafeInt) -> Result<SafeUint, ConversionError> {
+        if value.is_negative() {
+            return Err(ConversionError::Underflow { target: "SafeUint" });
+        }
+        match value.as_small() {
+            Some(small) => Ok(SafeUint::from(small as u128)),
+            None => Ok(SafeUint(value.big().magnitude().clone())),
+        }
+    }
+}
+
+impl TryFrom<SafeInt> for SafeUint {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: SafeInt) -> Result<SafeUint, ConversionError> {
+        if value.is_negative() {
+            return Err(ConversionError::Underflow { target: "SafeUint" });
+        }
+        Ok(SafeUint(value.into_raw().into_parts().1))
+    }
+}
+
+macro_rules! impl_uint_try_into_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl TryFrom<&SafeUint> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeUint) -> Result<$prim, ConversionError> {
+                    <$prim>::try_from(&value.0).map_err(|_| ConversionError::Overflow {
+                        target: stringify!($prim),
+                    })
+                }
+            }
+
+            impl TryFrom<SafeUint> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeUint) -> Result<$prim, ConversionError> {
+                    <$prim>::try_from(&value)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_try_into_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_uint_ops {
+    ($($trait:ident, $method:ident -> $output:ty, |$a:ident, $b:ident| $body:expr;)*) => {
+        $(
+            impl $trait<&SafeUint> for &SafeUint {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUint) -> $output {
+                    let ($a, $b) = (&self.0, &other.0);
+                    $body
+                }
+            }
+
+            impl $trait<SafeUint> for SafeUint {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUint) -> $output {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&SafeUint> for SafeUint {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUint) -> $output {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<SafeUint> for &SafeUint {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUint) -> $output {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_ops! {
+    Add, add -> SafeUint, |a, b| SafeUint(a + b);
+    Sub, sub -> Option<SafeUint>, |a, b| (a >= b).then(|| SafeUint(a - b));
+    Mul, mul -> SafeUint, |a, b| SafeUint(backend::mul_unsigned(a, b));
+    Div, div -> Option<SafeUint>, |a, b| {
+        (!b.is_zero()).then(|| SafeUint(backend::div_rem_unsigned(a, b).0))
+    };
+    Rem, rem -> Option<SafeUint>, |a, b| {
+        (!b.is_zero()).then(|| SafeUint(backend::div_rem_unsigned(a, b).1))
+    };
+    BitAnd, bitand -> SafeUint, |a, b| SafeUint(a & b);
+    BitOr, bitor -> SafeUint, |a, b| SafeUint(a | b);
+    BitXor, bitxor -> SafeUint, |a, b| SafeUint(a ^ b);
+}
+
+macro_rules! impl_uint_prim_ops {
+    ($prim:ty; $($trait:ident, $method:ident);*) => {
+        $(
+            impl $trait<$prim> for SafeUint {
+                type Output = <SafeUint as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Self::Output {
+                    (&self).$method(&SafeUint::from(other))
+                }
+            }
+
+            impl $trait<$prim> for &SafeUint {
+                type Output = <SafeUint as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Self::Output {
+                    self.$method(&SafeUint::from(other))
+                }
+            }
+
+            impl $trait<SafeUint> for $prim {
+                type Output = <SafeUint as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUint) -> Self::Output {
+                    (&SafeUint::from(self)).$method(&other)
+                }
+            }
+
+            impl $trait<&SafeUint> for $prim {
+                type Output = <SafeUint as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUint) -> Self::Output {
+                    (&SafeUint::from(self)).$method(other)
+                }
+            }
+        )*
+    };
+    ($($prim:ty),*) => {
+        $(
+            impl_uint_prim_ops!(
+                $prim;
+                Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem;
+                BitAnd, bitand; BitOr, bitor; BitXor, bitxor
+            );
+        )*
+    };
+}
+
+impl_uint_prim_ops!(u8, u16, u32, u64, u128, usize);
+
+/// Arithmetic against a signed operand `±magnitude` (`negative` implies a non-zero magnitude).
+/// Each helper yields `None` exactly where `SafeInt` would produce a negative result.
+impl SafeUint {
+    /// `self + ±magnitude`.
+    #[inline(always)]
+    fn add_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        match negative {
+            true => self - magnitude,
+            false => Some(self + magnitude),
+        }
+    }
+
+    /// `self - ±magnitude`.
+    #[inline(always)]
+    fn sub_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        self.add_signed(!negative, magnitude)
+    }
+
+    /// `±magnitude - self`.
+    #[inline(always)]
+    fn signed_sub(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        match negative {
+            true => None,
+            false => magnitude - self,
+        }
+    }
+
+    /// `self * ±magnitude`.
+    #[inline(always)]
+    fn mul_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        unless_negative(negative, self * magnitude)
+    }
+
+    /// `self / ±magnitude`, truncated toward zero.
+    #[inline(always)]
+    fn div_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        unless_negative(negative, (self / magnitude)?)
+    }
+
+    /// `±magnitude / self`, truncated toward zero.
+    #[inline(always)]
+    fn signed_div(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        unless_negative(negative, (magnitude / self)?)
+    }
+
+    /// `self % ±magnitude`; the remainder takes the sign of `self`.
+    #[inline(always)]
+    fn rem_signed(&self, _negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        self % magnitude
+    }
+
+    /// `±magnitude % self`; the remainder takes the sign of the operand.
+    #[inline(always)]
+    fn signed_rem(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        unless_negative(negative, (magnitude % self)?)
+    }
+
+    /// `self & ±magnitude` on the two's complement form, which is never negative.
+    #[inline(always)]
+    fn bitand_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        match negative {
+            // `-m` is `!(m - 1)`, so the AND clears the bits `m - 1` has set.
+            true => Some(SafeUint(&self.0 - (&self.0 & (&magnitude.0 - 1u8)))),
+            false => Some(self & magnitude),
+        }
+    }
+
+    /// `self | ±magnitude`; a negative operand keeps the result negative.
+    #[inline(always)]
+    fn bitor_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        (!negative).then(|| self | magnitude)
+    }
+
+    /// `self ^ ±magnitude`; a negative operand keeps the result negative.
+    #[inline(always)]
+    fn bitxor_signed(&self, negative: bool, magnitude: &SafeUint) -> Option<SafeUint> {
+        (!negative).then(|| self ^ magnitude)
+    }
+}
+
+/// Returns `value`, or `None` if it is the magnitude of a negative result.
+#[inline(always)]
+fn unless_negative(negative: bool, value: SafeUint) -> Option<SafeUint> {
+    (!negative || value.is_zero()).then_some(value)
+}
+
+/// Implements the operators against signed primitives. `$forward` computes `uint op prim` and
+/// `$reverse` computes `prim op uint`; every result is an `Option`, as a negative operand can
+/// take it below zero.
+macro_rules! impl_uint_signed_prim_ops {
+    ($prim:ty; $($trait:ident, $method:ident, $forward:ident, $reverse:ident);*) => {
+        $(
+            impl $trait<$prim> for &SafeUint {
+                type Output = Option<SafeUint>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeUint> {
+                    let magnitude = SafeUint::from((other as i128).unsigned_abs());
+                    self.$forward(other < 0, &magnitude)
+                }
+            }
+
+            impl $trait<$prim> for SafeUint {
+                type Output = Option<SafeUint>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeUint> {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<&SafeUint> for $prim {
+                type Output = Option<SafeUint>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUint) -> Option<SafeUint> {
+                    let magnitude = SafeUint::from((self as i128).unsigned_abs());
+                    other.$reverse(self < 0, &magnitude)
+                }
+            }
+
+            impl $trait<SafeUint> for $prim {
+                type Output = Option<SafeUint>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUint) -> Option<SafeUint> {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+    ($($prim:ty),*) => {
+        $(
+            impl_uint_signed_prim_ops!(
+                $prim;
+                Add, add, add_signed, add_signed;
+                Sub, sub, sub_signed, signed_sub;
+                Mul, mul, mul_signed, mul_signed;
+                Div, div, div_signed, signed_div;
+                Rem, rem, rem_signed, signed_rem;
+                BitAnd, bitand, bitand_signed, bitand_signed;
+                BitOr, bitor, bitor_signed, bitor_signed;
+                BitXor, bitxor, bitxor_signed, bitxor_signed
+            );
+        )*
+    };
+}
+
+impl_uint_signed_prim_ops!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_uint_assign_ops {
+    ($($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr;)*) => {
+        $(
+            impl $trait<&SafeUint> for SafeUint {
+                #[inline(always)]
+                fn $method(&mut self, other: &SafeUint) {
+                    let ($a, $b) = (&mut self.0, &other.0);
+                    $body
+                }
+            }
+
+            impl $trait<SafeUint> for SafeUint {
+                #[inline(always)]
+                fn $method(&mut self, other: SafeUint) {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_assign_ops! {
+    AddAssign, add_assign, |a, b| *a += b;
+    MulAssign, mul_assign, |a, b| *a = backend::mul_unsigned(a, b);
+    BitAndAssign, bitand_assign, |a, b| *a &= b;
+    BitOrAssign, bitor_assign, |a, b| *a |= b;
+    BitXorAssign, bitxor_assign, |a, b| *a ^= b;
+}
+
+macro_rules! impl_uint_assign_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl AddAssign<$prim> for SafeUint {
+                #[inline(always)]
+                fn add_assign(&mut self, other: $prim) {
+                    self.0 += other;
+                }
+            }
+
+            impl MulAssign<$prim> for SafeUint {
+                #[inline(always)]
+                fn mul_assign(&mut self, other: $prim) {
+                    self.0 *= other;
+                }
+            }
+
+            impl BitAndAssign<$prim> for SafeUint {
+                #[inline(always)]
+                fn bitand_assign(&mut self, other: $prim) {
+                    self.0 &= BigUint::from(other);
+                }
+            }
+
+            impl BitOrAssign<$prim> for SafeUint {
+                #[inline(always)]
+                fn bitor_assign(&mut self, other: $prim) {
+                    self.0 |= BigUint::from(other);
+                }
+            }
+
+            impl BitXorAssign<$prim> for SafeUint {
+                #[inline(always)]
+                fn bitxor_assign(&mut self, other: $prim) {
+                    self.0 ^= BigUint::from(other);
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_assign_for_primitive!(u8, u16, u32, u64, u128, usize);
+
+/// Division by zero leaves `self` unchanged, matching `SafeInt`'s `RemAssign`. Use the `/`
+/// operator directly to detect a zero divisor.
+impl<O> DivAssign<O> for SafeUint
+where
+    for<'a> &'a SafeUint: Div<O, Output = Option<SafeUint>>,
+{
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: O) {
+        if let Some(quotient) = (&*self).div(rhs) {
+            *self = quotient;
+        }
+    }
+}
+
+/// A zero divisor leaves `self` unchanged, matching `SafeInt`'s `RemAssign`.
+impl<O> RemAssign<O> for SafeUint
+where
+    for<'a> &'a SafeUint: Rem<O, Output = Option<SafeUint>>,
+{
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: O) {
+        if let Some(remainder) = (&*self).rem(rhs) {
+            *self = remainder;
+        }
+    }
+}
+
+macro_rules! impl_uint_cmp_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl PartialEq<$prim> for SafeUint {
+                #[inline(always)]
+                fn eq(&self, other: &$prim) -> bool {
+                    u128::try_from(*other)
+                        .is_ok_and(|other| u128::try_from(&self.0).ok() == Some(other))
+                }
+            }
+
+            impl PartialOrd<$prim> for SafeUint {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    // Negative primitives and magnitudes past `u128` order trivially.
+                    Some(match (u128::try_from(&self.0), u128::try_from(*other)) {
+                        (Ok(value), Ok(other)) => value.cmp(&other),
+                        (Err(_), Ok(_)) | (_, Err(_)) => Ordering::Greater,
+                    })
+                }
+            }
+
+            impl PartialEq<SafeUint> for $prim {
+                #[inline(always)]
+                fn eq(&self, other: &SafeUint) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<SafeUint> for $prim {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeUint) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_cmp_for_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl PartialEq<SafeInt> for SafeUint {
+    #[inline(always)]
+    fn eq(&self, other: &SafeInt) -> bool {
+        match other.as_small() {
+            Some(small) => {
+                u128::try_from(small).is_ok_and(|small| u128::try_from(&self.0).ok() == Some(small))
+            }
+            None => !other.is_negative() && *other.big().magnitude() == self.0,
+        }
+    }
+}
+
+impl PartialOrd<SafeInt> for SafeUint {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeInt) -> Option<Ordering> {
+        if other.is_negative() {
+            return Some(Ordering::Greater);
+        }
+        // An inline value fits a `u128`, so only a magnitude past that needs the `BigInt`.
+        match (other.as_small(), u128::try_from(&self.0)) {
+            (Some(small), Ok(value)) => Some(value.cmp(&(small as u128))),
+            (Some(_), Err(_)) => Some(Ordering::Greater),
+            (None, _) => Some(self.0.cmp(other.big().magnitude())),
+        }
+    }
+}
+
+impl PartialEq<SafeUint> for SafeInt {
+    #[inline(always)]
+    fn eq(&self, other: &SafeUint) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<SafeUint> for SafeInt {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeUint) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl fmt::Display for SafeUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &digits::to_decimal(&self.0))
+    }
+}
+
+macro_rules! impl_uint_radix_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl fmt::$trait for SafeUint {
+                #[inline(always)]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_radix_fmt!(LowerHex, UpperHex, Binary, Octal);
+
+/// Accepts the same literals as `SafeInt`; negative values fail with
+/// [`ParseError::OutOfRange`].
+impl FromStr for SafeUint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SafeUint::try_from(s.parse::<SafeInt>()?).map_err(|_| ParseError::OutOfRange)
+    }
+}
+
+/// Writes the magnitude in the `SafeInt` wire format without the zigzag step.
+impl Pack for SafeUint {
+    #[inline(always)]
+    fn pack(&self, writer: &mut impl Write) -> lencode::Result<usize> {
+        lencode_encode_biguint_with_variant(&self.0, writer)
+    }
+
+    #[inline(always)]
+    fn unpack(reader: &mut impl Read) -> lencode::Result<Self> {
+        Ok(SafeUint(lencode_decode_biguint_with_variant(
+            reader, false,
+        )?))
+    }
+}
+
+/// Large values are deduplicated under the same header as `SafeInt`.
+impl Encode for SafeUint {
+    #[inline(always)]
+    fn encode_ext(
+        &self,
+        writer: &mut impl Write,
+        dedupe_encoder: Option<&mut DedupeEncoder>,
+    ) -> lencode::Result<usize> {
+        if let Some(encoder) = dedupe_encoder
+            && self.0.bits() > 8 * LENCODE_SAFE_INT_DEDUPE_MIN_BYTES
+        {
+            let mut total = writer.write(&[LENCODE_SAFE_INT_VARIANT_DEDUPE])?;
+            total += encoder.encode(self, writer)?;
+            return Ok(total);
+        }
+        self.pack(writer)
+    }
+}
+
+impl Decode for SafeUint {
+    #[inline(always)]
+    fn decode_ext(
+        reader: &mut impl Read,
+        dedupe_decoder: Option<&mut DedupeDecoder>,
+    ) -> lencode::Result<Self> {
+        let tag = lencode_read_tag(reader)?;
+        if tag == LENCODE_SAFE_INT_VARIANT_DEDUPE
+            && let Some(decoder) = dedupe_decoder
+        {
+            return decoder.decode(reader);
+        }
+        Ok(SafeUint(lencode_decode_biguint_from_tag(
+            tag, reader, false,
+        )?))
+    }
+}
+
+impl SafeUint {
+    /// Decodes a `SafeUint`, rejecting any encoding that is not the canonical (minimal) one.
+    ///
+    /// The rules are those of [`SafeInt::decode_strict`], applied to the magnitude instead of
+    /// the zigzag value.
+    pub fn decode_strict(reader: &mut impl Read) -> lencode::Result<SafeUint> {
+        Ok(SafeUint(lencode_decode_biguint_with_variant(reader, true)?))
+    }
+}
+
+/// Non-negative fixed-precision decimal with `D` fractional digits, stored as a [`SafeUint`]
+/// scaled by `10^D`.
+///
+/// Operators follow [`SafeDec`]: multiplication truncates, division and remainder return
+/// `Option`, and subtraction also returns `Option`, yielding `None` when the result would be
+/// negative. As with [`SafeUint`], a signed primitive operand makes every operator return
+/// `Option`.
+///
+/// # Examples
+/// ```
+/// use safe_bigmath::{SafeDec, SafeUdec};
+///
+/// let price: SafeUdec<2> = "19.99".parse().unwrap();
+/// let total = &price * 3u8;
+/// assert_eq!(total.to_string(), "59.97");
+/// assert_eq!(&price - &total, None);
+/// assert_eq!((&total + -9i8).unwrap().to_string(), "50.97");
+///
+/// let signed = SafeDec::from(price);
+/// assert_eq!(signed.to_string(), "19.99");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SafeUdec<const D: usize>(SafeUint);
+
+impl<const D: usize> SafeUdec<D> {
+    /// Returns zero.
+    #[inline(always)]
+    pub fn zero() -> SafeUdec<D> {
+        SafeUdec(SafeUint::zero())
+    }
+
+    /// Wraps a raw value that is already scaled by `10^D`.
+    #[inline(always)]
+    pub fn from_raw(raw: SafeUint) -> Self {
+        SafeUdec(raw)
+    }
+
+    /// Returns the raw value, scaled by `10^D`.
+    #[inline(always)]
+    pub const fn raw(&self) -> &SafeUint {
+        &self.0
+    }
+
+    /// Decodes a `SafeUdec`, rejecting non-canonical encodings of the underlying scaled integer.
+    ///
+    /// See [`SafeUint::decode_strict`] for the canonical encoding rules.
+    pub fn decode_strict(reader: &mut impl Read) -> lencode::Result<Self> {
+        Ok(SafeUdec(SafeUint::decode_strict(reader)?))
+    }
+
+    #[inline(always)]
+    fn new(raw: BigUint) -> Self {
+        SafeUdec(SafeUint(raw))
+    }
+
+    /// Calls `f` with `10^D` as a `BigUint`.
+    #[inline(always)]
+    fn with_scale<R>(f: impl FnOnce(&BigUint) -> R) -> R {
+        f(&pow10_unsigned(D))
+    }
+
+    /// Returns the whole number `value` at scale `D`.
+    #[inline(always)]
+    fn whole(value: impl Into<BigUint>) -> Self {
+        let value = value.into();
+        Self::new(Self::with_scale(|scale| {
+            backend::mul_unsigned(&value, scale)
+        }))
+    }
+}
+
+impl<const D: usize> From<SafeUdec<D>> for SafeDec<D> {
+    #[inline(always)]
+    fn from(value: SafeUdec<D>) -> SafeDec<D> {
+        SafeDec::from_raw(SafeInt::from(value.0))
+    }
+}
+
+impl<const D: usize> From<&SafeUdec<D>> for SafeDec<D> {
+    #[inline(always)]
+    fn from(value: &SafeUdec<D>) -> SafeDec<D> {
+        SafeDec::from_raw(SafeInt::from(&value.0))
+    }
+}
+
+impl<const D: usize> TryFrom<&SafeDec<D>> for SafeUdec<D> {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: &SafeDec<D>) -> Result<SafeUdec<D>, ConversionError> {
+        SafeUint::try_from(value.raw())
+            .map(SafeUdec)
+            .map_err(|_| ConversionError::Underflow { target: "SafeUdec" })
+    }
+}
+
+impl<const D: usize> TryFrom<SafeDec<D>> for SafeUdec<D> {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(value: SafeDec<D>) -> Result<SafeUdec<D>, ConversionError> {
+        SafeUdec::try_from(&value)
+    }
+}
+
+macro_rules! impl_udec_try_into_primitive {
+    ($($prim:ty),*) => {
+        $(
+            /// Fails with [`ConversionError::Fractional`] if the value has a fractional part.
+            impl<const D: usize> TryFrom<&SafeUdec<D>> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: &SafeUdec<D>) -> Result<$prim, ConversionError> {
+                    <$prim>::try_from(SafeDec::from(value))
+                }
+            }
+
+            /// Fails with [`ConversionError::Fractional`] if the value has a fractional part.
+            impl<const D: usize> TryFrom<SafeUdec<D>> for $prim {
+                type Error = ConversionError;
+
+                #[inline(always)]
+                fn try_from(value: SafeUdec<D>) -> Result<$prim, ConversionError> {
+                    <$prim>::try_from(SafeDec::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_try_into_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_udec_ops {
+    ($($trait:ident, $method:ident -> $output:ty, |$a:ident, $b:ident| $body:expr;)*) => {
+        $(
+            impl<const D: usize> $trait<&SafeUdec<D>> for &SafeUdec<D> {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUdec<D>) -> $output {
+                    let ($a, $b) = (&self.0.0, &other.0.0);
+                    $body
+                }
+            }
+
+            impl<const D: usize> $trait<SafeUdec<D>> for SafeUdec<D> {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUdec<D>) -> $output {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl<const D: usize> $trait<&SafeUdec<D>> for SafeUdec<D> {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUdec<D>) -> $output {
+                    (&self).$method(other)
+                }
+            }
+
+            impl<const D: usize> $trait<SafeUdec<D>> for &SafeUdec<D> {
+                type Output = $output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUdec<D>) -> $output {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_ops! {
+    Add, add -> SafeUdec<D>, |a, b| SafeUdec::new(a + b);
+    Sub, sub -> Option<SafeUdec<D>>, |a, b| (a >= b).then(|| SafeUdec::new(a - b));
+    Mul, mul -> SafeUdec<D>, |a, b| {
+        let product = backend::mul_unsigned(a, b);
+        SafeUdec::new(SafeUdec::<D>::with_scale(|scale| {
+            backend::div_rem_unsigned(&product, scale).0
+        }))
+    };
+    Div, div -> Option<SafeUdec<D>>, |a, b| {
+        (!b.is_zero()).then(|| {
+            let scaled = SafeUdec::<D>::with_scale(|scale| backend::mul_unsigned(a, scale));
+            SafeUdec::new(backend::div_rem_unsigned(&scaled, b).0)
+        })
+    };
+    Rem, rem -> Option<SafeUdec<D>>, |a, b| {
+        (!b.is_zero()).then(|| SafeUdec::new(backend::div_rem_unsigned(a, b).1))
+    };
+    BitAnd, bitand -> SafeUdec<D>, |a, b| SafeUdec::new(a & b);
+    BitOr, bitor -> SafeUdec<D>, |a, b| SafeUdec::new(a | b);
+    BitXor, bitxor -> SafeUdec<D>, |a, b| SafeUdec::new(a ^ b);
+}
+
+macro_rules! impl_udec_prim_ops {
+    // Operators that treat the primitive as a whole number at scale `D`.
+    (@scaled $prim:ty; $($trait:ident, $method:ident);*) => {
+        $(
+            impl<const D: usize> $trait<$prim> for SafeUdec<D> {
+                type Output = <SafeUdec<D> as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Self::Output {
+                    (&self).$method(&SafeUdec::<D>::whole(other))
+                }
+            }
+
+            impl<const D: usize> $trait<$prim> for &SafeUdec<D> {
+                type Output = <SafeUdec<D> as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Self::Output {
+                    self.$method(&SafeUdec::<D>::whole(other))
+                }
+            }
+
+            impl<const D: usize> $trait<SafeUdec<D>> for $prim {
+                type Output = <SafeUdec<D> as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUdec<D>) -> Self::Output {
+                    (&SafeUdec::<D>::whole(self)).$method(&other)
+                }
+            }
+
+            impl<const D: usize> $trait<&SafeUdec<D>> for $prim {
+                type Output = <SafeUdec<D> as $trait>::Output;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUdec<D>) -> Self::Output {
+                    (&SafeUdec::<D>::whole(self)).$method(other)
+                }
+            }
+        )*
+    };
+    ($($prim:ty),*) => {
+        $(
+            impl_udec_prim_ops!(
+                @scaled $prim;
+                Add, add; Sub, sub; Rem, rem; BitAnd, bitand; BitOr, bitor; BitXor, bitxor
+            );
+
+            impl<const D: usize> Mul<$prim> for SafeUdec<D> {
+                type Output = SafeUdec<D>;
+
+                #[inline(always)]
+                fn mul(self, other: $prim) -> SafeUdec<D> {
+                    SafeUdec(self.0 * other)
+                }
+            }
+
+            impl<const D: usize> Mul<$prim> for &SafeUdec<D> {
+                type Output = SafeUdec<D>;
+
+                #[inline(always)]
+                fn mul(self, other: $prim) -> SafeUdec<D> {
+                    SafeUdec(&self.0 * other)
+                }
+            }
+
+            impl<const D: usize> Mul<SafeUdec<D>> for $prim {
+                type Output = SafeUdec<D>;
+
+                #[inline(always)]
+                fn mul(self, other: SafeUdec<D>) -> SafeUdec<D> {
+                    SafeUdec(self * other.0)
+                }
+            }
+
+            impl<const D: usize> Mul<&SafeUdec<D>> for $prim {
+                type Output = SafeUdec<D>;
+
+                #[inline(always)]
+                fn mul(self, other: &SafeUdec<D>) -> SafeUdec<D> {
+                    SafeUdec(self * &other.0)
+                }
+            }
+
+            impl<const D: usize> Div<$prim> for SafeUdec<D> {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn div(self, other: $prim) -> Option<SafeUdec<D>> {
+                    Some(SafeUdec((self.0 / other)?))
+                }
+            }
+
+            impl<const D: usize> Div<$prim> for &SafeUdec<D> {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn div(self, other: $prim) -> Option<SafeUdec<D>> {
+                    Some(SafeUdec((&self.0 / other)?))
+                }
+            }
+
+            impl<const D: usize> Div<SafeUdec<D>> for $prim {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn div(self, other: SafeUdec<D>) -> Option<SafeUdec<D>> {
+                    Some(SafeUdec((SafeUdec::<D>::whole(self).0 / other.0)?))
+                }
+            }
+
+            impl<const D: usize> Div<&SafeUdec<D>> for $prim {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn div(self, other: &SafeUdec<D>) -> Option<SafeUdec<D>> {
+                    Some(SafeUdec((SafeUdec::<D>::whole(self).0 / &other.0)?))
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_prim_ops!(u8, u16, u32, u64, u128, usize);
+
+/// Implements the operators against signed primitives on top of [`SafeUint`]'s signed helpers,
+/// with the primitive's magnitude taken as a whole number at scale `D` (`scaled`) or as a bare
+/// factor (`raw`), matching the unsigned impls above. Every result is an `Option`.
+macro_rules! impl_udec_signed_prim_ops {
+    (@operand scaled, $magnitude:expr) => {
+        SafeUdec::<D>::whole($magnitude).0
+    };
+    (@operand raw, $magnitude:expr) => {
+        SafeUint::from($magnitude)
+    };
+    (
+        $prim:ty;
+        $($trait:ident, $method:ident, $forward:ident($fwd_kind:ident), $reverse:ident($rev_kind:ident));*
+    ) => {
+        $(
+            impl<const D: usize> $trait<$prim> for &SafeUdec<D> {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeUdec<D>> {
+                    let magnitude = (other as i128).unsigned_abs();
+                    let operand = impl_udec_signed_prim_ops!(@operand $fwd_kind, magnitude);
+                    self.0.$forward(other < 0, &operand).map(SafeUdec)
+                }
+            }
+
+            impl<const D: usize> $trait<$prim> for SafeUdec<D> {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn $method(self, other: $prim) -> Option<SafeUdec<D>> {
+                    (&self).$method(other)
+                }
+            }
+
+            impl<const D: usize> $trait<&SafeUdec<D>> for $prim {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn $method(self, other: &SafeUdec<D>) -> Option<SafeUdec<D>> {
+                    let magnitude = (self as i128).unsigned_abs();
+                    let operand = impl_udec_signed_prim_ops!(@operand $rev_kind, magnitude);
+                    other.0.$reverse(self < 0, &operand).map(SafeUdec)
+                }
+            }
+
+            impl<const D: usize> $trait<SafeUdec<D>> for $prim {
+                type Output = Option<SafeUdec<D>>;
+
+                #[inline(always)]
+                fn $method(self, other: SafeUdec<D>) -> Option<SafeUdec<D>> {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+    ($($prim:ty),*) => {
+        $(
+            impl_udec_signed_prim_ops!(
+                $prim;
+                Add, add, add_signed(scaled), add_signed(scaled);
+                Sub, sub, sub_signed(scaled), signed_sub(scaled);
+                Mul, mul, mul_signed(raw), mul_signed(raw);
+                Div, div, div_signed(raw), signed_div(scaled);
+                Rem, rem, rem_signed(scaled), signed_rem(scaled);
+                BitAnd, bitand, bitand_signed(scaled), bitand_signed(scaled);
+                BitOr, bitor, bitor_signed(scaled), bitor_signed(scaled);
+                BitXor, bitxor, bitxor_signed(scaled), bitxor_signed(scaled)
+            );
+        )*
+    };
+}
+
+impl_udec_signed_prim_ops!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_udec_assign_ops {
+    ($($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr;)*) => {
+        $(
+            impl<const D: usize> $trait<&SafeUdec<D>> for SafeUdec<D> {
+                #[inline(always)]
+                fn $method(&mut self, other: &SafeUdec<D>) {
+                    let ($a, $b) = (&mut self.0, &other.0);
+                    $body
+                }
+            }
+
+            impl<const D: usize> $trait<SafeUdec<D>> for SafeUdec<D> {
+                #[inline(always)]
+                fn $method(&mut self, other: SafeUdec<D>) {
+                    self.$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_assign_ops! {
+    AddAssign, add_assign, |a, b| *a += b;
+    MulAssign, mul_assign, |a, b| {
+        let product = backend::mul_unsigned(&a.0, &b.0);
+        a.0 = SafeUdec::<D>::with_scale(|scale| backend::div_rem_unsigned(&product, scale).0);
+    };
+    BitAndAssign, bitand_assign, |a, b| *a &= b;
+    BitOrAssign, bitor_assign, |a, b| *a |= b;
+    BitXorAssign, bitxor_assign, |a, b| *a ^= b;
+}
+
+macro_rules! impl_udec_assign_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl<const D: usize> AddAssign<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn add_assign(&mut self, other: $prim) {
+                    self.0 += SafeUdec::<D>::whole(other).0;
+                }
+            }
+
+            impl<const D: usize> MulAssign<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn mul_assign(&mut self, other: $prim) {
+                    self.0 *= other;
+                }
+            }
+
+            impl<const D: usize> BitAndAssign<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn bitand_assign(&mut self, other: $prim) {
+                    self.0 &= SafeUdec::<D>::whole(other).0;
+                }
+            }
+
+            impl<const D: usize> BitOrAssign<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn bitor_assign(&mut self, other: $prim) {
+                    self.0 |= SafeUdec::<D>::whole(other).0;
+                }
+            }
+
+            impl<const D: usize> BitXorAssign<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn bitxor_assign(&mut self, other: $prim) {
+                    self.0 ^= SafeUdec::<D>::whole(other).0;
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_assign_for_primitive!(u8, u16, u32, u64, u128, usize);
+
+/// Division by zero leaves `self` unchanged, matching `SafeDec`'s `DivAssign`. Use the `/`
+/// operator directly to detect a zero divisor.
+impl<const D: usize, O> DivAssign<O> for SafeUdec<D>
+where
+    for<'a> &'a SafeUdec<D>: Div<O, Output = Option<SafeUdec<D>>>,
+{
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: O) {
+        if let Some(quotient) = (&*self).div(rhs) {
+            *self = quotient;
+        }
+    }
+}
+
+/// A zero divisor leaves `self` unchanged, matching `SafeDec`'s `RemAssign`.
+impl<const D: usize, O> RemAssign<O> for SafeUdec<D>
+where
+    for<'a> &'a SafeUdec<D>: Rem<O, Output = Option<SafeUdec<D>>>,
+{
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: O) {
+        if let Some(remainder) = (&*self).rem(rhs) {
+            *self = remainder;
+        }
+    }
+}
+
+macro_rules! impl_udec_cmp_for_primitive {
+    ($($prim:ty),*) => {
+        $(
+            impl<const D: usize> PartialEq<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn eq(&self, other: &$prim) -> bool {
+                    u128::try_from(*other).is_ok_and(|other| *self == SafeUdec::<D>::whole(other))
+                }
+            }
+
+            impl<const D: usize> PartialOrd<$prim> for SafeUdec<D> {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    match u128::try_from(*other) {
+                        Ok(other) => self.partial_cmp(&SafeUdec::<D>::whole(other)),
+                        Err(_) => Some(Ordering::Greater),
+                    }
+                }
+            }
+
+            impl<const D: usize> PartialEq<SafeUdec<D>> for $prim {
+                #[inline(always)]
+                fn eq(&self, other: &SafeUdec<D>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<const D: usize> PartialOrd<SafeUdec<D>> for $prim {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &SafeUdec<D>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+impl_udec_cmp_for_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl<const D: usize> PartialEq<SafeDec<D>> for SafeUdec<D> {
+    #[inline(always)]
+    fn eq(&self, other: &SafeDec<D>) -> bool {
+        self.0 == *other.raw()
+    }
+}
+
+impl<const D: usize> PartialOrd<SafeDec<D>> for SafeUdec<D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeDec<D>) -> Option<Ordering> {
+        self.0.partial_cmp(other.raw())
+    }
+}
+
+impl<const D: usize> PartialEq<SafeUdec<D>> for SafeDec<D> {
+    #[inline(always)]
+    fn eq(&self, other: &SafeUdec<D>) -> bool {
+        other == self
+    }
+}
+
+impl<const D: usize> PartialOrd<SafeUdec<D>> for SafeDec<D> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &SafeUdec<D>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Formats exactly like the equal [`SafeDec`], including precision and padding flags.
+impl<const D: usize> fmt::Display for SafeUdec<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&SafeDec::from(self), f)
+    }
+}
+
+/// Accepts the same literals as `SafeDec`; negative values fail with
+/// [`ParseError::OutOfRange`].
+impl<const D: usize> FromStr for SafeUdec<D> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SafeUdec::try_from(s.parse::<SafeDec<D>>()?).map_err(|_| ParseError::OutOfRange)
+    }
+}
+
+impl<const D: usize> Encode for SafeUdec<D> {
+    #[inline(always)]
+    fn encode_ext(
+        &self,
+        writer: &mut impl Write,
+        dedupe_encoder: Option<&mut DedupeEncoder>,
+    ) -> lencode::Result<usize> {
+        self.0.encode_ext(writer, dedupe_encoder)
+    }
+}
+
+impl<const D: usize> Decode for SafeUdec<D> {
+    #[inline(always)]
+    fn decode_ext(
+        reader: &mut impl Read,
+        dedupe_decoder: Option<&mut DedupeDecoder>,
+    ) -> lencode::Result<Self> {
+        Ok(SafeUdec(SafeUint::decode_ext(reader, dedupe_decoder)?))
+    }
+}
+
+#[test]
+fn test_safe_uint_matches_safe_int() {
+    let values = [
+        SafeUint::zero(),
+        SafeUint::one(),
+        SafeUint::from(7u8),
+        SafeUint::from(u64::MAX),
+        SafeUint::from(u128::MAX),
+        SafeUint::from(u128::MAX).pow(3),
+    ];
+    for a in &values {
+        let signed_a = SafeInt::from(a);
+        assert_eq!(SafeUint::try_from(&signed_a).unwrap(), *a);
+        for b in &values {
+            let signed_b = SafeInt::from(b);
+            assert_eq!(a + b, &signed_a + &signed_b);
+            assert_eq!(a * b, &signed_a * &signed_b);
+            assert_eq!(a & b, &signed_a & &signed_b);
+            assert_eq!(a | b, &signed_a | &signed_b);
+            assert_eq!(a ^ b, &signed_a ^ &signed_b);
+            assert_eq!(a.partial_cmp(b), signed_a.partial_cmp(&signed_b));
+            assert_eq!(a.partial_cmp(&signed_b), signed_a.partial_cmp(&signed_b));
+            let difference = &signed_a - &signed_b;
+            match a - b {
+                Some(result) => assert_eq!(result, difference),
+                None => assert!(difference.is_negative()),
+            }
+            assert_eq!(
+                (a / b).map(SafeInt::from),
+                &signed_a / &signed_b,
+                "{a} / {b}"
+            );
+            assert_eq!((a % b).map(SafeInt::from), &signed_a % &signed_b);
+        }
+    }
+
+    // Inline `SafeInt`s compare without building a `BigInt`, including against magnitudes past
+    // `u128`.
+    let huge = SafeUint::from(u128::MAX).pow(2);
+    let (minus_one, inline_max) = (SafeInt::from(-1), SafeInt::from(i128::MAX));
+    assert!(huge != minus_one && huge > minus_one);
+    assert!(huge > inline_max && SafeUint::zero() < inline_max);
+    assert_eq!(SafeInt::from(&huge), SafeInt::from(u128::MAX).pow(2));
+
+    // Scales past the `u128` powers of ten still rescale exactly.
+    let one = SafeUdec::<40>::zero() + 1u8;
+    let third = (&one / 3u8).unwrap();
+    assert_eq!((&third * 3u8).to_string(), format!("0.{}", "9".repeat(40)));
+    assert_eq!(&third * &(&one * 3u8), &third * 3u8);
+    let inverse = (&one / &third).unwrap();
+    assert_eq!(inverse.to_string(), format!("3.{}3", "0".repeat(39)));
+}
+
+#[test]
+fn test_safe_uint_primitives_and_conversions() {
+    let value = SafeUint::from(200u8);
+    assert_eq!(&value + 100u8, 300u16);
+    assert_eq!(&value - 201u8, None);
+    assert_eq!(1000u16 - &value, Some(SafeUint::from(800u16)));
+    assert_eq!(3u8 * &value, 600);
+    assert_eq!(&value / 0u8, None);
+    assert_eq!(201u8 % value.clone(), Some(SafeUint::one()));
+    assert!(value > -1i8 && value < 201i64 && value == 200i32);
+    assert!(-5i32 < value && 500u128 > value);
+    assert!(SafeUint::from(u128::MAX) * 2u8 > u128::MAX);
+
+    let mut acc = SafeUint::zero();
+    acc += 5u8;
+    acc *= &SafeUint::from(4u8);
+    acc /= 0u8;
+    assert_eq!(acc, 20);
+    acc %= 6u8;
+    assert_eq!(acc, 2);
+
+    let big = SafeUint::from(u128::MAX) * 3u8;
+    let other = SafeUint::from(0x1234_5678u32);
+    let mut assigned = big.clone();
+    assigned += &other;
+    assigned *= other.clone();
+    assigned &= &big;
+    assigned |= 0xF0u8;
+    assigned ^= other.clone();
+    assert_eq!(
+        assigned,
+        ((((&big + &other) * &other) & &big) | 0xF0u8) ^ &other
+    );
+
+    assert_eq!(u8::try_from(&value), Ok(200));
+    assert_eq!(
+        i8::try_from(&value),
+        Err(ConversionError::Overflow { target: "i8" })
+    );
+    assert_eq!(
+        SafeUint::try_from(SafeInt::from(-1)),
+        Err(ConversionError::Underflow { target: "SafeUint" })
+    );
+    assert_eq!(SafeUint::from(5u8).abs_diff(&SafeUint::from(9u8)), 4);
+    assert_eq!(SafeUint::from(5u8).saturating_sub(&SafeUint::from(9u8)), 0);
+
+    let big = SafeUint::from(u128::MAX).pow(2) + 7u8;
+    assert_eq!(SafeUint::from_u64_digits(&big.to_u64_digits()), big);
+    assert_eq!(big.to_u64_digits(), SafeInt::from(&big).to_u64_digits().1);
+    assert_eq!(SafeUint::from_bytes_le(&big.to_bytes_le()), big);
+    assert_eq!(SafeUint::from_bytes_be(&big.to_bytes_be()), big);
+    assert_eq!(SafeUint::zero().to_bytes_be(), [0]);
+}
+
+#[test]
+fn test_safe_uint_signed_primitives_match_safe_int() {
+    let values = [
+        SafeUint::zero(),
+        SafeUint::one(),
+        SafeUint::from(6u8),
+        SafeUint::from(7u8),
+        SafeUint::from(u128::MAX),
+        SafeUint::from(u128::MAX).pow(2),
+    ];
+    let nonnegative = |value: SafeInt| SafeUint::try_from(value).ok();
+    for a in &values {
+        let signed_a = SafeInt::from(a);
+        for prim in [0i64, 1, -1, 6, -6, 7, -7, i64::MIN, i64::MAX] {
+            let b = SafeInt::from(prim);
+            assert_eq!(a + prim, nonnegative(&signed_a + &b), "{a} + {prim}");
+            assert_eq!(prim + a, nonnegative(&b + &signed_a), "{prim} + {a}");
+            assert_eq!(a - prim, nonnegative(&signed_a - &b), "{a} - {prim}");
+            assert_eq!(prim - a, nonnegative(&b - &signed_a), "{prim} - {a}");
+            assert_eq!(a * prim, nonnegative(&signed_a * &b), "{a} * {prim}");
+            assert_eq!(prim * a, nonnegative(&b * &signed_a), "{prim} * {a}");
+            assert_eq!(
+                a / prim,
+                (&signed_a / &b).and_then(nonnegative),
+                "{a} / {prim}"
+            );
+            assert_eq!(
+                prim / a,
+                (&b / &signed_a).and_then(nonnegative),
+                "{prim} / {a}"
+            );
+            assert_eq!(
+                a % prim,
+                (&signed_a % &b).and_then(nonnegative),
+                "{a} % {prim}"
+            );
+            assert_eq!(
+                prim % a,
+                (&b % &signed_a).and_then(nonnegative),
+                "{prim} % {a}"
+            );
+            assert_eq!(a & prim, nonnegative(&signed_a & &b), "{a} & {prim}");
+            assert_eq!(prim & a, nonnegative(&b & &signed_a), "{prim} & {a}");
+            assert_eq!(a | prim, nonnegative(&signed_a | &b), "{a} | {prim}");
+            assert_eq!(a ^ prim, nonnegative(&signed_a ^ &b), "{a} ^ {prim}");
+        }
+    }
+    assert_eq!(SafeUint::from(5u8) + -5i8, Some(SafeUint::zero()));
+    assert_eq!(SafeUint::from(u8::MAX) & i128::MIN, Some(SafeUint::zero()));
+
+    let mut balance = SafeUint::from(100u8);
+    balance /= -1i32;
+    assert_eq!(balance, 100);
+    balance %= -7isize;
+    assert_eq!(balance, 2);
+}
+
+#[test]
+fn test_safe_uint_display_and_parse() {
+    let value: SafeUint = "0xff".parse().unwrap();
+    assert_eq!(value, 255);
+    assert_eq!(
+        format!("{value:>6}|{value:x}|{value:#b}"),
+        "   255|ff|0b11111111"
+    );
+    assert_eq!("-3".parse::<SafeUint>(), Err(ParseError::OutOfRange));
+    assert_eq!("-0".parse::<SafeUint>().unwrap(), 0);
+    let big = SafeUint::from(10u8).pow(50);
+    assert_eq!(big.to_string().parse::<SafeUint>().unwrap(), big);
+}
+
+#[test]
+fn test_safe_uint_lencode_skips_zigzag() {
+    // 63 still fits the one-byte form, which a zigzag `SafeInt` would need two bytes for.
+    let mut bytes = Vec::new();
+    SafeUint::from(63u8).encode(&mut bytes).unwrap();
+    assert_eq!(bytes, [63]);
+    bytes.clear();
+    SafeInt::from(63).encode(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 2);
+
+    for value in [
+        SafeUint::zero(),
+        SafeUint::from(64u8),
+        SafeUint::from(u128::MAX),
+        SafeUint::from(7u8).pow(500),
+    ] {
+        let mut bytes = Vec::new();
+        value.encode(&mut bytes).unwrap();
+        assert_eq!(
+            SafeUint::decode(&mut Cursor::new(&bytes[..])).unwrap(),
+            value
+        );
+        assert_eq!(
+            SafeUint::decode_strict(&mut Cursor::new(&bytes[..])).unwrap(),
+            value
+        );
+    }
+    assert!(SafeUint::decode_strict(&mut Cursor::new(&[0x41, 0x01][..])).is_err());
+}
+
+#[test]
+fn test_safe_uint_lencode_dedupe_roundtrip() {
+    let value = SafeUint::from(u128::MAX);
+    let mut encoder = DedupeEncoder::new();
+    let mut bytes = Vec::new();
+    value.encode_ext(&mut bytes, Some(&mut encoder)).unwrap();
+    let first = bytes.len();
+    value.encode_ext(&mut bytes, Some(&mut encoder)).unwrap();
+    assert!(bytes.len() - first < first);
+
+    let mut decoder = DedupeDecoder::new();
+    let mut cursor = Cursor::new(&bytes[..]);
+    for _ in 0..2 {
+        let decoded = SafeUint::decode_ext(&mut cursor, Some(&mut decoder)).unwrap();
+        assert_eq!(decoded, value);
+    }
+}
+
+#[test]
+fn test_safe_udec_matches_safe_dec() {
+    let values = [
+        "0.00",
+        "0.01",
+        "1.50",
+        "19.99",
+        "1234567890123456789012345.67",
+    ];
+    for a in values {
+        let (a, signed_a) = (
+            a.parse::<SafeUdec<2>>().unwrap(),
+            a.parse::<SafeDec<2>>().unwrap(),
+        );
+        assert_eq!(a, signed_a);
+        for b in values {
+            let b_unsigned = b.parse::<SafeUdec<2>>().unwrap();
+            let signed_b = b.parse::<SafeDec<2>>().unwrap();
+            assert_eq!(&a + &b_unsigned, &signed_a + &signed_b);
+            assert_eq!(&a * &b_unsigned, signed_a.clone() * signed_b.clone());
+            assert_eq!((&a / &b_unsigned).map(SafeDec::from), &signed_a / &signed_b);
+            assert_eq!((&a % &b_unsigned).map(SafeDec::from), &signed_a % &signed_b);
+            match &a - &b_unsigned {
+                Some(result) => assert_eq!(result, &signed_a - &signed_b),
+                None => assert!(a < b_unsigned),
+            }
+            for prim in [0u32, 3, 1000] {
+                assert_eq!(&a + prim, &signed_a + prim);
+                assert_eq!(&a * prim, &signed_a * prim);
+                assert_eq!((&a / prim).map(SafeDec::from), &signed_a / prim);
+                assert_eq!((prim / &a).map(SafeDec::from), prim / &signed_a);
+                assert_eq!(a.partial_cmp(&prim), signed_a.partial_cmp(&prim));
+            }
+            let nonnegative = |value: SafeDec<2>| SafeUdec::try_from(value).ok();
+            for prim in [0i32, 3, -3, 1000, -1000] {
+                assert_eq!(&a + prim, nonnegative(&signed_a + prim), "{a} + {prim}");
+                assert_eq!(prim - &a, nonnegative(prim - &signed_a), "{prim} - {a}");
+                assert_eq!(&a * prim, nonnegative(&signed_a * prim), "{a} * {prim}");
+                let quotient = (&signed_a / prim).and_then(nonnegative);
+                assert_eq!(&a / prim, quotient, "{a} / {prim}");
+                let quotient = (prim / &signed_a).and_then(nonnegative);
+                assert_eq!(prim / &a, quotient, "{prim} / {a}");
+                let remainder = (&signed_a % prim).and_then(nonnegative);
+                assert_eq!(&a % prim, remainder, "{a} % {prim}");
+                assert_eq!(&a & prim, nonnegative(&signed_a & prim), "{a} & {prim}");
+                assert_eq!(&a | prim, nonnegative(&signed_a | prim), "{a} | {prim}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_safe_udec_conversions_and_format() {
+    let value: SafeUdec<3> = "2.500".parse().unwrap();
+    assert_eq!(value.raw(), &2500u16);
+    assert_eq!(format!("{value:.1}|{value:>7}"), "2.5|  2.500");
+    assert!(value > -3i8 && value < 3u8);
+    assert_eq!(&value - 3u8, None);
+    assert_eq!((5u8 - &value).unwrap().to_string(), "2.500");
+    assert_eq!(
+        u8::try_from(&value),
+        Err(ConversionError::Fractional { target: "u8" })
+    );
+    assert_eq!(u8::try_from(value * 2u8), Ok(5));
+    assert_eq!("-0.500".parse::<SafeUdec<3>>(), Err(ParseError::OutOfRange));
+    assert_eq!(
+        SafeUdec::try_from("-0.500".parse::<SafeDec<3>>().unwrap()),
+        Err(ConversionError::Underflow { target: "SafeUdec" })
+    );
+
+    let mut total = SafeUdec::<2>::zero();
+    total += 3u8;
+    total *= "1.50".parse::<SafeUdec<2>>().unwrap();
+    total /= 0u8;
+    assert_eq!(total.to_string(), "4.50");
+
+    let rate = "1.25".parse::<SafeUdec<2>>().unwrap();
+    let mut assigned = total.clone();
+    assigned += &rate;
+    assigned *= &rate;
+    assigned *= 3u8;
+    assigned |= 2u8;
+    assigned &= rate.clone();
+    assigned ^= 1u8;
+    assert_eq!(
+        assigned,
+        ((((&total + &rate) * &rate * 3u8) | 2u8) & &rate) ^ 1u8
+    );
+
+    let mut bytes = Vec::new();
+    total.encode(&mut bytes).unwrap();
+    assert_eq!(
+        SafeUdec::<2>::decode(&mut Cursor::new(&bytes[..])).unwrap(),
+        total
+    );
+    assert_eq!(
+        SafeUdec::<2>::decode_strict(&mut Cursor::new(&bytes[..])).unwrap(),
+        total
+    );
+}
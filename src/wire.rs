@@ -0,0 +1,44 @@
+//! Header layout of the lencode wire format shared by `SafeInt`, `SafeUint` and the
+//! fixed-capacity types.
+//!
+//! The first byte is `V S PPPPPP`. With `V = 0` and `S = 0` the payload `P` is the value
+//! itself; with `V = 0` and `S = 1` it is the length of the little-endian payload that
+//! follows. `V = 1` marks lencode's byte-slice encoding (`S = 0`) or a dedupe reference
+//! (`S = 1`).
+
+pub(crate) const LENCODE_SAFE_INT_VARIANT_MASK: u8 = 0x80;
+pub(crate) const LENCODE_SAFE_INT_VARIANT_BYTES: u8 = 0x80;
+pub(crate) const LENCODE_SAFE_INT_SIZE_MASK: u8 = 0x40;
+pub(crate) const LENCODE_SAFE_INT_SIZE_LARGE: u8 = 0x40;
+pub(crate) const LENCODE_SAFE_INT_PAYLOAD_MASK: u8 = 0x3F;
+pub(crate) const LENCODE_SAFE_INT_SMALL_MAX: u8 = LENCODE_SAFE_INT_PAYLOAD_MASK;
+pub(crate) const LENCODE_MAX_VARINT_BYTES: usize = LENCODE_SAFE_INT_PAYLOAD_MASK as usize;
+/// Header used for values routed through lencode's dedupe tables (`V=1`, `S=1`). Only ever
+/// written when a `DedupeEncoder` is supplied.
+#[cfg(feature = "alloc")]
+pub(crate) const LENCODE_SAFE_INT_VARIANT_DEDUPE: u8 =
+    LENCODE_SAFE_INT_VARIANT_BYTES | LENCODE_SAFE_INT_SIZE_LARGE;
+/// Zigzag payloads longer than this many bytes are deduplicated when a `DedupeEncoder` is
+/// supplied; smaller values are cheaper to repeat inline than to reference.
+#[cfg(feature = "alloc")]
+pub(crate) const LENCODE_SAFE_INT_DEDUPE_MIN_BYTES: u64 = 8;
+
+/// Reads one of lencode's unsigned varints from the start of `bytes`: a byte below `0x80` is
+/// the value, otherwise its low seven bits count the little-endian bytes that follow.
+///
+/// Returns the value and the number of bytes read, or `None` if `bytes` is too short or the
+/// value does not fit a `u64`.
+pub(crate) fn read_lencode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let (&first, rest) = bytes.split_first()?;
+    if first & 0x80 == 0 {
+        return Some((u64::from(first), 1));
+    }
+    let len = usize::from(first & 0x7F);
+    let value = rest.get(..len)?;
+    if len > 8 {
+        return None;
+    }
+    let mut le = [0u8; 8];
+    le[..len].copy_from_slice(value);
+    Some((u64::from_le_bytes(le), 1 + len))
+}